@@ -0,0 +1,48 @@
+-- event enum
+DO $$
+BEGIN
+    CREATE TYPE event AS ENUM (
+        'GUILD_CREATE',
+        'GUILD_DELETE',
+        'INVITE_CHECK_CREATE'
+    );
+EXCEPTION
+    WHEN duplicate_object THEN NULL;
+END $$;
+
+-- event_log table
+CREATE TABLE IF NOT EXISTS public.event_log (
+    event_type event NOT NULL,
+    payload JSONB NOT NULL DEFAULT '{}'::JSONB,
+    created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+
+-- guild table
+CREATE TABLE IF NOT EXISTS public.guild (
+    guild_id INT8 PRIMARY KEY,
+    category_channel_ids INT8[] NOT NULL DEFAULT '{}',
+    ignored_channel_ids INT8[] NOT NULL DEFAULT '{}',
+    embed_color INT4 NOT NULL DEFAULT 16316671,
+    results_channel_id INT8
+);
+
+-- invite table
+CREATE TABLE IF NOT EXISTS public.invite (
+    code TEXT PRIMARY KEY,
+    is_permalink BOOLEAN DEFAULT NULL,
+    is_valid BOOLEAN DEFAULT NULL,
+    expires_at TIMESTAMP WITH TIME ZONE DEFAULT NULL,
+    created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at TIMESTAMP WITH TIME ZONE DEFAULT NULL
+);
+
+-- message table
+CREATE TABLE IF NOT EXISTS public.message (
+    guild_id INT8,
+    channel_id INT8,
+    message_id INT8,
+    parent_id INT8 NOT NULL,
+    invite_codes TEXT[] NOT NULL DEFAULT '{}',
+    created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    PRIMARY KEY (guild_id, channel_id, message_id)
+);
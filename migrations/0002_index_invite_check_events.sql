@@ -0,0 +1,4 @@
+-- speeds up reading a guild's invite check history
+CREATE INDEX IF NOT EXISTS event_log_invite_check_guild_id_idx
+    ON public.event_log (((payload->>'guild_id')::INT8), created_at DESC)
+    WHERE event_type = 'INVITE_CHECK_CREATE';
@@ -0,0 +1,17 @@
+-- event_log ids, so that invite checks can be referenced
+ALTER TABLE public.event_log
+    ADD COLUMN IF NOT EXISTS event_id INT8 GENERATED BY DEFAULT AS IDENTITY PRIMARY KEY;
+
+-- invite_check_channel table
+CREATE TABLE IF NOT EXISTS public.invite_check_channel (
+    check_id INT8 REFERENCES public.event_log (event_id) ON DELETE CASCADE,
+    channel_id INT8,
+    category_id INT8 NOT NULL,
+    position INT4 NOT NULL,
+    valid_invites INT4 NOT NULL DEFAULT 0,
+    invalid_invites INT4 NOT NULL DEFAULT 0,
+    unknown_invites INT4 NOT NULL DEFAULT 0,
+    is_ignored BOOLEAN NOT NULL DEFAULT FALSE,
+    is_untracked BOOLEAN NOT NULL DEFAULT FALSE,
+    PRIMARY KEY (check_id, channel_id)
+);
@@ -0,0 +1,3 @@
+-- cron expression (in UTC) for scheduled invite checks
+ALTER TABLE public.guild
+    ADD COLUMN IF NOT EXISTS check_schedule TEXT DEFAULT NULL;
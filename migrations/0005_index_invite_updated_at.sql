@@ -0,0 +1,2 @@
+-- invite revalidation index
+CREATE INDEX IF NOT EXISTS invite_updated_at_idx ON public.invite (updated_at NULLS FIRST, created_at);
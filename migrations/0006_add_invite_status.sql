@@ -0,0 +1,29 @@
+-- invite_status enum
+DO $$
+BEGIN
+    CREATE TYPE invite_status AS ENUM (
+        'VALID',
+        'UNKNOWN_INVITE',
+        'GUILD_UNAVAILABLE',
+        'RATE_LIMITED',
+        'NETWORK_ERROR'
+    );
+EXCEPTION
+    WHEN duplicate_object THEN NULL;
+END $$;
+
+-- outcome of the latest attempt to resolve an invite, and the Discord error code (or HTTP status)
+ALTER TABLE public.invite
+    ADD COLUMN IF NOT EXISTS status invite_status DEFAULT NULL,
+    ADD COLUMN IF NOT EXISTS error_code INT8 DEFAULT NULL;
+
+UPDATE
+    public.invite
+SET
+    status = CASE
+        WHEN is_valid THEN 'VALID'::invite_status
+        ELSE 'UNKNOWN_INVITE'::invite_status
+    END
+WHERE
+    updated_at IS NOT NULL
+    AND status IS NULL;
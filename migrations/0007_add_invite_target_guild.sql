@@ -0,0 +1,12 @@
+-- guild that each invite points to
+ALTER TABLE public.invite
+    ADD COLUMN IF NOT EXISTS target_guild_id INT8 DEFAULT NULL,
+    ADD COLUMN IF NOT EXISTS target_guild_name TEXT DEFAULT NULL,
+    ADD COLUMN IF NOT EXISTS member_count INT4 DEFAULT NULL,
+    ADD COLUMN IF NOT EXISTS online_count INT4 DEFAULT NULL;
+
+-- partner guild rules
+ALTER TABLE public.guild
+    ADD COLUMN IF NOT EXISTS minimum_member_count INT4 DEFAULT NULL,
+    ADD COLUMN IF NOT EXISTS allow_duplicate_guilds BOOLEAN NOT NULL DEFAULT TRUE,
+    ADD COLUMN IF NOT EXISTS blocked_guild_ids INT8[] NOT NULL DEFAULT '{}';
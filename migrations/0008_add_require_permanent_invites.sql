@@ -0,0 +1,6 @@
+-- whether expiring or limited-use invites are reported as temporary
+ALTER TABLE public.guild
+    ADD COLUMN IF NOT EXISTS require_permanent_invites BOOLEAN NOT NULL DEFAULT FALSE;
+
+ALTER TABLE public.invite_check_channel
+    ADD COLUMN IF NOT EXISTS temporary_invites INT4 NOT NULL DEFAULT 0;
@@ -0,0 +1,4 @@
+-- daily warnings about invites that are about to expire
+ALTER TABLE public.guild
+    ADD COLUMN IF NOT EXISTS alerts_channel_id INT8 DEFAULT NULL,
+    ADD COLUMN IF NOT EXISTS expiry_warning_days INT4 DEFAULT NULL;
@@ -0,0 +1,3 @@
+-- whether invites that turn invalid are reported as they are resolved
+ALTER TABLE public.guild
+    ADD COLUMN IF NOT EXISTS invalid_invite_alerts BOOLEAN NOT NULL DEFAULT FALSE;
@@ -0,0 +1,4 @@
+-- how many messages to scan per channel, and whose messages count
+ALTER TABLE public.guild
+    ADD COLUMN IF NOT EXISTS scan_depth INT4 NOT NULL DEFAULT 10,
+    ADD COLUMN IF NOT EXISTS scan_author_ids INT8[] NOT NULL DEFAULT '{}';
@@ -0,0 +1,60 @@
+-- invite_counting_mode enum
+DO $$
+BEGIN
+    CREATE TYPE invite_counting_mode AS ENUM (
+        'ALL',
+        'FIRST',
+        'LATEST',
+        'PINNED'
+    );
+EXCEPTION
+    WHEN duplicate_object THEN NULL;
+END $$;
+
+-- which messages in a channel count towards its invites
+ALTER TABLE public.guild
+    ADD COLUMN IF NOT EXISTS invite_counting_mode invite_counting_mode NOT NULL DEFAULT 'ALL';
+
+ALTER TABLE public.message
+    ADD COLUMN IF NOT EXISTS is_pinned BOOLEAN NOT NULL DEFAULT FALSE;
+
+-- messages whose invites count, given each guild's counting mode
+CREATE OR REPLACE VIEW public.counted_message AS
+    SELECT
+        public.message.guild_id,
+        public.message.channel_id,
+        public.message.message_id,
+        public.message.invite_codes
+    FROM
+        public.message
+        JOIN public.guild ON public.guild.guild_id = public.message.guild_id
+    WHERE
+        public.guild.invite_counting_mode = 'ALL'
+        OR (
+            public.guild.invite_counting_mode = 'PINNED'
+            AND public.message.is_pinned
+        )
+        OR (
+            public.guild.invite_counting_mode = 'FIRST'
+            AND public.message.message_id = (
+                SELECT
+                    MIN(other_message.message_id)
+                FROM
+                    public.message other_message
+                WHERE
+                    other_message.guild_id = public.message.guild_id
+                    AND other_message.channel_id = public.message.channel_id
+            )
+        )
+        OR (
+            public.guild.invite_counting_mode = 'LATEST'
+            AND public.message.message_id = (
+                SELECT
+                    MAX(other_message.message_id)
+                FROM
+                    public.message other_message
+                WHERE
+                    other_message.guild_id = public.message.guild_id
+                    AND other_message.channel_id = public.message.channel_id
+            )
+        );
@@ -0,0 +1,44 @@
+-- where in each message its invite codes were found
+ALTER TABLE public.message
+    ADD COLUMN IF NOT EXISTS invite_locations JSONB NOT NULL DEFAULT '{}';
+
+CREATE OR REPLACE VIEW public.counted_message AS
+    SELECT
+        public.message.guild_id,
+        public.message.channel_id,
+        public.message.message_id,
+        public.message.invite_codes,
+        public.message.invite_locations
+    FROM
+        public.message
+        JOIN public.guild ON public.guild.guild_id = public.message.guild_id
+    WHERE
+        public.guild.invite_counting_mode = 'ALL'
+        OR (
+            public.guild.invite_counting_mode = 'PINNED'
+            AND public.message.is_pinned
+        )
+        OR (
+            public.guild.invite_counting_mode = 'FIRST'
+            AND public.message.message_id = (
+                SELECT
+                    MIN(other_message.message_id)
+                FROM
+                    public.message other_message
+                WHERE
+                    other_message.guild_id = public.message.guild_id
+                    AND other_message.channel_id = public.message.channel_id
+            )
+        )
+        OR (
+            public.guild.invite_counting_mode = 'LATEST'
+            AND public.message.message_id = (
+                SELECT
+                    MAX(other_message.message_id)
+                FROM
+                    public.message other_message
+                WHERE
+                    other_message.guild_id = public.message.guild_id
+                    AND other_message.channel_id = public.message.channel_id
+            )
+        );
@@ -0,0 +1,10 @@
+-- channel_rule table, for expectations that invite checks grade single channels against
+CREATE TABLE IF NOT EXISTS public.channel_rule (
+    guild_id INT8 REFERENCES public.guild (guild_id) ON DELETE CASCADE,
+    channel_id INT8,
+    minimum_invites INT4,
+    maximum_invites INT4,
+    allow_temporary_invites BOOLEAN NOT NULL DEFAULT FALSE,
+    owner_id INT8,
+    PRIMARY KEY (guild_id, channel_id)
+);
@@ -0,0 +1,3 @@
+-- rules that ignore channels by name, topic or type instead of by id
+ALTER TABLE public.guild
+    ADD COLUMN IF NOT EXISTS ignore_rules JSONB NOT NULL DEFAULT '[]';
@@ -0,0 +1,3 @@
+-- channels that are checked without their whole category, or outside of one
+ALTER TABLE public.guild
+    ADD COLUMN IF NOT EXISTS tracked_channel_ids INT8[] NOT NULL DEFAULT '{}';
@@ -0,0 +1,14 @@
+-- check_profile table, for separate partner programs that are checked on their own
+CREATE TABLE IF NOT EXISTS public.check_profile (
+    guild_id INT8 REFERENCES public.guild (guild_id) ON DELETE CASCADE,
+    name TEXT,
+    category_channel_ids INT8[] NOT NULL DEFAULT '{}',
+    ignored_channel_ids INT8[] NOT NULL DEFAULT '{}',
+    embed_color INT4 NOT NULL DEFAULT 16316671,
+    results_channel_id INT8,
+    check_schedule TEXT,
+    minimum_member_count INT4,
+    allow_duplicate_guilds BOOLEAN NOT NULL DEFAULT TRUE,
+    require_permanent_invites BOOLEAN NOT NULL DEFAULT FALSE,
+    PRIMARY KEY (guild_id, name)
+);
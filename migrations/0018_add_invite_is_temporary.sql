@@ -0,0 +1,10 @@
+-- whether an invite expires or has limited uses, which is not the same as
+-- is_permalink for guilds with a vanity URL
+ALTER TABLE public.invite
+    ADD COLUMN IF NOT EXISTS is_temporary BOOLEAN DEFAULT NULL;
+
+UPDATE public.invite
+SET
+    is_temporary = expires_at IS NOT NULL
+WHERE
+    is_valid;
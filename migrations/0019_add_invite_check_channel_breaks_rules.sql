@@ -0,0 +1,3 @@
+-- whether a channel broke a partner rule, so that diff mode can report it
+ALTER TABLE public.invite_check_channel
+    ADD COLUMN IF NOT EXISTS breaks_rules BOOLEAN NOT NULL DEFAULT FALSE;
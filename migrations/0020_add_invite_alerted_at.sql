@@ -0,0 +1,10 @@
+-- when an invalid invite was last alerted about, kept when the invite is
+-- posted again so that known invalid invites are not alerted twice
+ALTER TABLE public.invite
+    ADD COLUMN IF NOT EXISTS alerted_at TIMESTAMP WITH TIME ZONE DEFAULT NULL;
+
+UPDATE public.invite
+SET
+    alerted_at = updated_at
+WHERE
+    NOT is_valid;
@@ -0,0 +1,14 @@
+-- partner rules and tracked channels that belong to a single check profile
+ALTER TABLE public.check_profile
+    ADD COLUMN IF NOT EXISTS blocked_guild_ids INT8[] NOT NULL DEFAULT '{}',
+    ADD COLUMN IF NOT EXISTS ignore_rules JSONB NOT NULL DEFAULT '[]',
+    ADD COLUMN IF NOT EXISTS tracked_channel_ids INT8[] NOT NULL DEFAULT '{}';
+
+-- channel rules without a profile apply to the guild's own invite checks, the
+-- profile key lets both kinds of rules share the primary key
+ALTER TABLE public.channel_rule
+    ADD COLUMN IF NOT EXISTS profile TEXT,
+    ADD COLUMN IF NOT EXISTS profile_key TEXT GENERATED ALWAYS AS (COALESCE(profile, '')) STORED,
+    ADD FOREIGN KEY (guild_id, profile) REFERENCES public.check_profile (guild_id, name) ON DELETE CASCADE,
+    DROP CONSTRAINT IF EXISTS channel_rule_pkey,
+    ADD PRIMARY KEY (guild_id, profile_key, channel_id);
@@ -0,0 +1,87 @@
+use twilight_interactions::command::{CommandModel, CreateCommand};
+use twilight_model::guild::Permissions;
+use twilight_util::builder::embed::EmbedBuilder;
+
+use crate::{
+    types::{
+        context::Context,
+        interaction::{
+            ApplicationCommandInteraction,
+            DeferInteractionPayload,
+            UpdateResponsePayload,
+        },
+        Result,
+    },
+    utility::error::Error,
+};
+
+#[derive(CommandModel, CreateCommand)]
+#[command(
+    desc = "Cancel the invite check or category that Sakura is working on",
+    name = "cancel"
+)]
+pub struct CheckCancelCommand {
+    #[command(
+        desc = "The check profile whose invite check to cancel, instead of the server's own",
+        max_length = 32
+    )]
+    profile: Option<String>,
+}
+
+impl CheckCancelCommand {
+    pub async fn run(
+        context: &Context,
+        interaction: &mut ApplicationCommandInteraction<'_>,
+        options: Self,
+    ) -> Result<()> {
+        interaction
+            .context
+            .defer(DeferInteractionPayload {
+                ephemeral: false,
+            })
+            .await?;
+
+        if !interaction
+            .member_permissions
+            .contains(Permissions::MANAGE_GUILD)
+        {
+            return Err(Error::Custom(
+                "You need the **Manage Server** permission to cancel invite checks.".to_owned(),
+            ));
+        }
+
+        let Some(guild_job) = context
+            .cache
+            .cancel_guild_job(interaction.guild_id, options.profile.as_deref())
+        else {
+            return Err(Error::Custom(match options.profile {
+                Some(profile) => {
+                    format!("Sakura is not running an invite check for `{profile}` at the moment.")
+                }
+                None => {
+                    "Sakura is not running an invite check or adding a category at the moment."
+                        .to_owned()
+                }
+            }));
+        };
+
+        let embed = EmbedBuilder::new()
+            .color(0xF8F8FF)
+            .description(format!(
+                "Sakura has stopped {} (started <t:{}:R>).",
+                guild_job.description(),
+                guild_job.started_at.unix_timestamp()
+            ))
+            .build();
+
+        interaction
+            .context
+            .update_response(UpdateResponsePayload {
+                embeds: vec![embed],
+                ..Default::default()
+            })
+            .await?;
+
+        Ok(())
+    }
+}
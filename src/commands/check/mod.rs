@@ -0,0 +1,34 @@
+mod cancel;
+mod run;
+
+use twilight_interactions::command::{CommandModel, CreateCommand};
+
+use self::{cancel::CheckCancelCommand, run::CheckRunCommand};
+use crate::types::{context::Context, interaction::ApplicationCommandInteraction, Result};
+
+#[derive(CommandModel, CreateCommand)]
+#[command(desc = "Run or cancel invite checks", name = "check")]
+pub enum CheckCommand {
+    #[command(name = "cancel")]
+    Cancel(CheckCancelCommand),
+    #[command(name = "run")]
+    Run(CheckRunCommand),
+}
+
+impl CheckCommand {
+    pub async fn run(
+        context: &Context,
+        interaction: &mut ApplicationCommandInteraction<'_>,
+    ) -> Result<()> {
+        match CheckCommand::from_interaction(interaction.input_data())? {
+            CheckCommand::Cancel(options) => {
+                CheckCancelCommand::run(context, interaction, options).await?
+            }
+            CheckCommand::Run(options) => {
+                CheckRunCommand::run(context, interaction, options).await?
+            }
+        }
+
+        Ok(())
+    }
+}
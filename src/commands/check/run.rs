@@ -0,0 +1,81 @@
+use twilight_interactions::command::{CommandModel, CreateCommand};
+use twilight_util::builder::embed::EmbedBuilder;
+
+use crate::types::{
+    check::CheckOptions,
+    context::Context,
+    interaction::{ApplicationCommandInteraction, DeferInteractionPayload, UpdateResponsePayload},
+    Result,
+};
+
+#[derive(CommandModel, CreateCommand)]
+#[command(desc = "Run an invite check", name = "run")]
+pub struct CheckRunCommand {
+    #[command(desc = "Only show channels that changed since the last invite check")]
+    diff: Option<bool>,
+    #[command(
+        desc = "The check profile to run instead of the server's own settings",
+        max_length = 32
+    )]
+    profile: Option<String>,
+}
+
+impl CheckRunCommand {
+    pub async fn run(
+        context: &Context,
+        interaction: &mut ApplicationCommandInteraction<'_>,
+        options: Self,
+    ) -> Result<()> {
+        interaction
+            .context
+            .defer(DeferInteractionPayload {
+                ephemeral: false,
+            })
+            .await?;
+
+        let invite_check = context
+            .prepare_invite_check(
+                interaction.guild_id,
+                CheckOptions {
+                    diff: options.diff.unwrap_or_default(),
+                    profile: options.profile,
+                },
+            )
+            .await?;
+        let results_channel_id = invite_check.results_channel_id()?;
+        let start_embed = EmbedBuilder::new()
+            .color(invite_check.embed_color())
+            .description("Sakura is checking your invites now!")
+            .build();
+
+        if interaction.channel_id.eq(&results_channel_id) {
+            interaction
+                .context
+                .update_response(UpdateResponsePayload {
+                    embeds: vec![start_embed],
+                    ..Default::default()
+                })
+                .await?;
+        } else {
+            interaction
+                .context
+                .update_response(UpdateResponsePayload {
+                    embeds: vec![EmbedBuilder::new()
+                        .color(invite_check.embed_color())
+                        .description(format!("Results will be sent in <#{results_channel_id}>!"))
+                        .build()],
+                    ..Default::default()
+                })
+                .await?;
+            context
+                .http
+                .create_message(results_channel_id)
+                .embeds(&[start_embed])?
+                .await?;
+        }
+
+        let report = invite_check.run().await?;
+
+        invite_check.send_report(results_channel_id, &report).await
+    }
+}
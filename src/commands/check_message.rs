@@ -11,7 +11,7 @@ use crate::{
         },
         Result,
     },
-    utility::{error::Error, message::get_invite_codes},
+    utility::error::Error,
 };
 
 pub struct CheckMessageCommand {}
@@ -33,46 +33,37 @@ impl CheckMessageCommand {
             .await?;
 
         let Some(message) = interaction.message() else {
-            return Err(Error::Custom("I could not find a message.".to_owned()))
+            return Err(Error::Custom("I could not find a message.".to_owned()));
         };
         let Some(channel) = context.cache.get_channel(message.channel_id) else {
             return Err(Error::Custom(
-                "Sakura only looks at messages in announcement and text channels.".to_owned(),
-            ))
+                "Sakura only looks at messages in announcement channels, text channels, threads and forum posts.".to_owned(),
+            ));
         };
-        let Some(parent_id) = channel.parent_id else {
+        let Some(parent_id) = context.cache.get_checked_category_id(&channel) else {
             return Err(Error::Custom(
-                "Please ensure this message is within a category before checking it."
-                    .to_owned(),
-            ))
-        };
-
-        if !context
-            .cache
-            .get_guild(interaction.guild_id)
-            .map_or(false, |cached_guild| {
-                cached_guild
-                    .invite_check_category_ids
-                    .read()
-                    .contains(&parent_id)
-            })
-        {
-            return Err(Error::Custom(
-                "Please ensure this message is within an **added** category before checking it."
+                "Please ensure this message is within an **added** category or a **tracked** channel before checking it."
                     .to_owned(),
             ));
-        }
+        };
 
-        let invite_codes = get_invite_codes(message.content, message.embeds);
+        let invite_codes = context
+            .get_message_invite_codes(
+                &message.content,
+                &message.embeds,
+                &message.components,
+                &message.attachments,
+            )
+            .await;
 
         if invite_codes.is_empty() {
             return Err(Error::Custom("No invite codes found.".to_owned()));
         }
 
-        for invite_code in invite_codes.iter() {
+        for invite_code in invite_codes.keys() {
             context
                 .database
-                .insert_unchecked_invite(&invite_code)
+                .insert_unchecked_invite(invite_code)
                 .await?;
         }
 
@@ -84,6 +75,7 @@ impl CheckMessageCommand {
                 message.id,
                 parent_id,
                 invite_codes,
+                Some(message.pinned),
             )
             .await?;
 
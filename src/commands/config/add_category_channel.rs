@@ -1,13 +1,10 @@
-use std::time::Duration;
-
-use tokio::time::sleep;
 use twilight_interactions::command::{CommandModel, CreateCommand};
 use twilight_model::id::{marker::ChannelMarker, Id};
 use twilight_util::builder::embed::EmbedBuilder;
 
 use crate::{
     types::{
-        cache::GuildUpdate,
+        cache::{GuildJobKind, GuildUpdate},
         context::Context,
         interaction::{
             ApplicationCommandInteraction,
@@ -16,7 +13,7 @@ use crate::{
         },
         Result,
     },
-    utility::{error::Error, message::get_invite_codes},
+    utility::error::Error,
 };
 
 #[derive(CommandModel, CreateCommand)]
@@ -42,10 +39,13 @@ impl ConfigAddCategoryChannelCommand {
             })
             .await?;
 
-        let Some(cached_guild) = context.cache.get_guild(interaction.guild_id) else {
+        let (Some(cached_guild), Some(database_guild)) = (
+            context.cache.get_guild(interaction.guild_id),
+            context.database.get_guild(interaction.guild_id).await,
+        ) else {
             return Err(Error::Custom(
                 "Please kick and re-invite Sakura.".to_owned(),
-            ))
+            ));
         };
         let category_id = options.channel;
 
@@ -59,78 +59,14 @@ impl ConfigAddCategoryChannelCommand {
             )));
         }
 
-        if cached_guild.in_check {
-            return Err(Error::Custom(
-                "Sakura is either running an invite check or adding a category at the
-        moment. Please wait until this is done before trying again."
-                    .to_owned(),
-            ));
-        }
-
-        let mut channel_ids_to_process = Vec::new();
-        let mut invisible_channels = Vec::new();
-
-        for channel_id in cached_guild.channel_ids.read().clone().into_iter() {
-            let channel = match context.cache.get_channel(channel_id) {
-                None => continue,
-                Some(channel) => channel,
-            };
-
-            if channel
-                .parent_id
-                .map_or(true, |parent_id| !parent_id.eq(&category_id))
-            {
-                continue;
-            }
-
-            if context.cache.has_minimum_channel_permissions(channel_id) {
-                channel_ids_to_process.push(channel_id)
-            } else {
-                invisible_channels.push(format!("- <#{channel_id}>"))
-            }
-        }
+        let guild_job = context
+            .cache
+            .lock_guild(interaction.guild_id, GuildJobKind::AddCategoryChannel)?;
 
-        if !invisible_channels.is_empty() {
-            return Err(Error::Custom(format!("Sakura is unable to check the following channels:\n{}\nPlease give permission for Sakura to read these channels and add the category again.", invisible_channels.join("\n")),));
-        }
-
-        for channel_id in channel_ids_to_process {
-            sleep(Duration::from_millis(1000)).await;
-
-            let messages = context
-                .http
-                .channel_messages(channel_id)
-                .limit(10)?
-                .await?
-                .model()
-                .await?;
-
-            for message in messages {
-                sleep(Duration::from_millis(100)).await;
-
-                let invite_codes = get_invite_codes(message.content, message.embeds);
-
-                for invite_code in invite_codes.iter() {
-                    sleep(Duration::from_millis(100)).await;
-
-                    context
-                        .database
-                        .insert_unchecked_invite(invite_code)
-                        .await?;
-                }
-
-                context
-                    .database
-                    .insert_message(
-                        interaction.guild_id,
-                        channel_id,
-                        message.id,
-                        category_id,
-                        invite_codes,
-                    )
-                    .await?;
-            }
-        }
+        context
+            .scan_category(&database_guild, &guild_job, category_id)
+            .await?;
+        guild_job.ensure_not_cancelled()?;
 
         let updated_category_channel_ids = context
             .database
@@ -140,7 +76,6 @@ impl ConfigAddCategoryChannelCommand {
         context.cache.update_guild(
             interaction.guild_id,
             GuildUpdate {
-                in_check: Some(false),
                 invite_check_category_ids: Some(updated_category_channel_ids),
                 ..Default::default()
             },
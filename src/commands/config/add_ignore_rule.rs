@@ -0,0 +1,165 @@
+use fancy_regex::Regex;
+use twilight_interactions::command::{CommandModel, CommandOption, CreateCommand, CreateOption};
+use twilight_model::channel::ChannelType;
+use twilight_util::builder::embed::EmbedBuilder;
+
+use crate::{
+    types::{
+        context::Context,
+        database::IgnoreRule,
+        interaction::{
+            ApplicationCommandInteraction,
+            DeferInteractionPayload,
+            UpdateResponsePayload,
+        },
+        Result,
+    },
+    utility::{constants::GUILD_MEDIA_CHANNEL_TYPE, error::Error},
+};
+
+#[derive(CommandOption, CreateOption)]
+pub enum IgnoredChannelType {
+    #[option(name = "Announcement channels", value = "announcement")]
+    Announcement,
+    #[option(name = "Forum channels", value = "forum")]
+    Forum,
+    #[option(name = "Media channels", value = "media")]
+    Media,
+    #[option(name = "Text channels", value = "text")]
+    Text,
+}
+
+#[derive(CommandModel, CreateCommand)]
+#[command(
+    desc = "Ignore every channel that matches a rule during an invite check",
+    name = "add-ignore-rule"
+)]
+pub struct ConfigAddIgnoreRuleCommand {
+    #[command(
+        desc = "Ignore channels whose name matches this pattern, such as *rules*",
+        max_length = 100,
+        rename = "name-glob"
+    )]
+    name_glob: Option<String>,
+    #[command(
+        desc = "Ignore channels whose name matches this regular expression",
+        max_length = 100,
+        rename = "name-regex"
+    )]
+    name_regex: Option<String>,
+    #[command(
+        desc = "Ignore channels whose topic contains this keyword",
+        max_length = 100,
+        rename = "topic-keyword"
+    )]
+    topic_keyword: Option<String>,
+    #[command(desc = "Ignore channels of this type", rename = "channel-type")]
+    channel_type: Option<IgnoredChannelType>,
+}
+
+impl ConfigAddIgnoreRuleCommand {
+    pub async fn run(
+        context: &Context,
+        interaction: &mut ApplicationCommandInteraction<'_>,
+        options: Self,
+    ) -> Result<()> {
+        interaction
+            .context
+            .defer(DeferInteractionPayload {
+                ephemeral: false,
+            })
+            .await?;
+
+        let Some(database_guild) = context.database.get_guild(interaction.guild_id).await else {
+            return Err(Error::Custom(
+                "Please kick and re-invite Sakura.".to_owned(),
+            ));
+        };
+        let ignore_rule = get_ignore_rule(
+            options.name_glob,
+            options.name_regex,
+            options.topic_keyword,
+            options.channel_type,
+        )?;
+
+        if let IgnoreRule::NameRegex {
+            pattern,
+        } = &ignore_rule
+        {
+            if Regex::new(pattern).is_err() {
+                return Err(Error::Custom(format!(
+                    "`{pattern}` is not a valid regular expression."
+                )));
+            }
+        }
+
+        if database_guild.ignore_rules.contains(&ignore_rule) {
+            return Err(Error::Custom("This is already an ignore rule.".to_owned()));
+        }
+
+        context
+            .database
+            .insert_ignore_rule(interaction.guild_id, &ignore_rule)
+            .await?;
+
+        let embed = EmbedBuilder::new()
+            .color(0xF8F8FF)
+            .description(format!(
+                "{} will now be ignored during invite checks.",
+                ignore_rule.description()
+            ))
+            .build();
+
+        interaction
+            .context
+            .update_response(UpdateResponsePayload {
+                embeds: vec![embed],
+                ..Default::default()
+            })
+            .await?;
+
+        Ok(())
+    }
+}
+
+pub fn get_ignore_rule(
+    name_glob: Option<String>,
+    name_regex: Option<String>,
+    topic_keyword: Option<String>,
+    channel_type: Option<IgnoredChannelType>,
+) -> Result<IgnoreRule> {
+    let ignore_rule = match (name_glob, name_regex, topic_keyword, channel_type) {
+        (Some(pattern), None, None, None) => {
+            IgnoreRule::NameGlob {
+                pattern,
+            }
+        }
+        (None, Some(pattern), None, None) => {
+            IgnoreRule::NameRegex {
+                pattern,
+            }
+        }
+        (None, None, Some(keyword), None) => {
+            IgnoreRule::TopicKeyword {
+                keyword,
+            }
+        }
+        (None, None, None, Some(channel_type)) => {
+            IgnoreRule::ChannelType {
+                channel_type: u8::from(match channel_type {
+                    IgnoredChannelType::Announcement => ChannelType::GuildAnnouncement,
+                    IgnoredChannelType::Forum => ChannelType::GuildForum,
+                    IgnoredChannelType::Media => GUILD_MEDIA_CHANNEL_TYPE,
+                    IgnoredChannelType::Text => ChannelType::GuildText,
+                }),
+            }
+        }
+        _ => {
+            return Err(Error::Custom(
+                "Please choose exactly one of `name-glob`, `name-regex`, `topic-keyword` or `channel-type`.".to_owned(),
+            ))
+        }
+    };
+
+    Ok(ignore_rule)
+}
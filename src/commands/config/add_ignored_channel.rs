@@ -22,8 +22,8 @@ use crate::{
 )]
 pub struct ConfigAddIgnoredChannelCommand {
     #[command(
-        channel_types = "guild_news guild_text",
-        desc = "The announcement or text channel"
+        channel_types = "guild_forum guild_news guild_news_thread guild_public_thread guild_text",
+        desc = "The announcement, text or forum channel, thread or forum post"
     )]
     channel: Id<ChannelMarker>,
 }
@@ -44,7 +44,7 @@ impl ConfigAddIgnoredChannelCommand {
         let Some(database_guild) = context.database.get_guild(interaction.guild_id).await else {
             return Err(Error::Custom(
                 "Please kick and re-invite Sakura.".to_owned(),
-            ))
+            ));
         };
         let channel_id = options.channel;
 
@@ -0,0 +1,87 @@
+use twilight_interactions::command::{CommandModel, CreateCommand};
+use twilight_model::id::Id;
+use twilight_util::builder::embed::EmbedBuilder;
+
+use crate::{
+    types::{
+        context::Context,
+        interaction::{
+            ApplicationCommandInteraction,
+            DeferInteractionPayload,
+            UpdateResponsePayload,
+        },
+        Result,
+    },
+    utility::error::Error,
+};
+
+#[derive(CommandModel, CreateCommand)]
+#[command(
+    desc = "Only scan messages from this bot, webhook or user",
+    name = "add-scan-author"
+)]
+pub struct ConfigAddScanAuthorCommand {
+    #[command(desc = "The ID of the bot, webhook or user", rename = "author-id")]
+    author_id: String,
+}
+
+impl ConfigAddScanAuthorCommand {
+    pub async fn run(
+        context: &Context,
+        interaction: &mut ApplicationCommandInteraction<'_>,
+        options: Self,
+    ) -> Result<()> {
+        interaction
+            .context
+            .defer(DeferInteractionPayload {
+                ephemeral: false,
+            })
+            .await?;
+
+        let Some(database_guild) = context.database.get_guild(interaction.guild_id).await else {
+            return Err(Error::Custom(
+                "Please kick and re-invite Sakura.".to_owned(),
+            ));
+        };
+        let Some(author_id) = options
+            .author_id
+            .trim()
+            .parse()
+            .ok()
+            .and_then(Id::new_checked)
+        else {
+            return Err(Error::Custom(format!(
+                "**{}** is not a valid ID.",
+                options.author_id
+            )));
+        };
+
+        if database_guild.scan_author_ids.contains(&author_id) {
+            return Err(Error::Custom(format!(
+                "Messages from **{author_id}** are already scanned."
+            )));
+        }
+
+        context
+            .database
+            .insert_scan_author(interaction.guild_id, author_id)
+            .await?;
+
+        let embed = EmbedBuilder::new()
+            .color(0xF8F8FF)
+            .description(format!(
+                "Sakura will now only scan messages from **{author_id}** and the other scanned authors."
+            ))
+            .build();
+
+        interaction
+            .context
+            .update_response(UpdateResponsePayload {
+                embeds: vec![embed],
+                ..Default::default()
+            })
+            .await?;
+
+        Ok(())
+    }
+}
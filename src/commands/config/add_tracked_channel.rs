@@ -0,0 +1,149 @@
+use std::time::Duration;
+
+use tokio::time::sleep;
+use twilight_interactions::command::{CommandModel, CreateCommand};
+use twilight_model::id::{marker::ChannelMarker, Id};
+use twilight_util::builder::embed::EmbedBuilder;
+
+use crate::{
+    types::{
+        cache::{GuildJobKind, GuildUpdate},
+        context::Context,
+        interaction::{
+            ApplicationCommandInteraction,
+            DeferInteractionPayload,
+            UpdateResponsePayload,
+        },
+        Result,
+    },
+    utility::error::Error,
+};
+
+#[derive(CommandModel, CreateCommand)]
+#[command(
+    desc = "Add a channel for Sakura to check outside of an added category",
+    name = "add-tracked-channel"
+)]
+pub struct ConfigAddTrackedChannelCommand {
+    #[command(
+        channel_types = "guild_forum guild_news guild_text",
+        desc = "The announcement, text or forum channel"
+    )]
+    channel: Id<ChannelMarker>,
+}
+
+impl ConfigAddTrackedChannelCommand {
+    pub async fn run(
+        context: &Context,
+        interaction: &mut ApplicationCommandInteraction<'_>,
+        options: Self,
+    ) -> Result<()> {
+        interaction
+            .context
+            .defer(DeferInteractionPayload {
+                ephemeral: false,
+            })
+            .await?;
+
+        let (Some(cached_guild), Some(database_guild)) = (
+            context.cache.get_guild(interaction.guild_id),
+            context.database.get_guild(interaction.guild_id).await,
+        ) else {
+            return Err(Error::Custom(
+                "Please kick and re-invite Sakura.".to_owned(),
+            ));
+        };
+        let channel_id = options.channel;
+        let Some(channel) = context.cache.get_channel(channel_id) else {
+            return Err(Error::Custom(
+                "Sakura only tracks announcement, text, forum and media channels.".to_owned(),
+            ));
+        };
+
+        if cached_guild
+            .tracked_channel_ids
+            .read()
+            .contains(&channel_id)
+        {
+            return Err(Error::Custom(format!(
+                "<#{channel_id}> is already a tracked channel."
+            )));
+        }
+
+        if channel.parent_id.is_some_and(|parent_id| {
+            cached_guild
+                .invite_check_category_ids
+                .read()
+                .contains(&parent_id)
+        }) {
+            return Err(Error::Custom(format!(
+                "<#{channel_id}> is already checked as part of an added category."
+            )));
+        }
+
+        if !context.cache.has_minimum_channel_permissions(channel_id) {
+            return Err(Error::Custom(format!("Sakura is unable to check <#{channel_id}>. Please give permission for Sakura to read the channel and add it again.")));
+        }
+
+        let guild_job = context
+            .cache
+            .lock_guild(interaction.guild_id, GuildJobKind::AddTrackedChannel)?;
+
+        if channel.is_forum() {
+            context.cache_archived_threads(channel_id).await?;
+        }
+
+        let mut channel_ids_to_process = context
+            .cache
+            .get_thread_ids(interaction.guild_id, channel_id);
+
+        if !channel.is_forum() {
+            channel_ids_to_process.insert(0, channel_id);
+        }
+
+        for channel_id_to_process in channel_ids_to_process {
+            sleep(Duration::from_millis(1000)).await;
+            guild_job.ensure_not_cancelled()?;
+
+            context
+                .scan_channel(
+                    &database_guild,
+                    channel_id_to_process,
+                    interaction.guild_id.cast(),
+                )
+                .await?;
+        }
+
+        guild_job.ensure_not_cancelled()?;
+
+        let updated_tracked_channel_ids = context
+            .database
+            .insert_tracked_channel(interaction.guild_id, channel_id)
+            .await?;
+
+        context.cache.update_guild(
+            interaction.guild_id,
+            GuildUpdate {
+                tracked_channel_ids: Some(updated_tracked_channel_ids),
+                ..Default::default()
+            },
+        );
+
+        let embed = EmbedBuilder::new()
+            .color(0xF8F8FF)
+            .description(format!(
+                "<#{channel_id}> will now be checked during invite checks."
+            ))
+            .build();
+
+        interaction
+            .context
+            .update_response(UpdateResponsePayload {
+                embeds: vec![embed],
+                ..Default::default()
+            })
+            .await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,81 @@
+use twilight_interactions::command::{CommandModel, CreateCommand};
+use twilight_model::id::{marker::ChannelMarker, Id};
+use twilight_util::builder::embed::EmbedBuilder;
+
+use super::{get_check_profile, get_invite_checks_name};
+use crate::{
+    types::{
+        context::Context,
+        interaction::{
+            ApplicationCommandInteraction,
+            DeferInteractionPayload,
+            UpdateResponsePayload,
+        },
+        Result,
+    },
+    utility::error::Error,
+};
+
+#[derive(CommandModel, CreateCommand)]
+#[command(desc = "Remove every rule for a channel", name = "clear")]
+pub struct ConfigChannelClearCommand {
+    #[command(
+        channel_types = "guild_news guild_news_thread guild_public_thread guild_text",
+        desc = "The announcement channel, text channel, thread or forum post"
+    )]
+    channel: Id<ChannelMarker>,
+    #[command(
+        desc = "The check profile the rule belongs to, instead of the server's own",
+        max_length = 32
+    )]
+    profile: Option<String>,
+}
+
+impl ConfigChannelClearCommand {
+    pub async fn run(
+        context: &Context,
+        interaction: &mut ApplicationCommandInteraction<'_>,
+        options: Self,
+    ) -> Result<()> {
+        interaction
+            .context
+            .defer(DeferInteractionPayload {
+                ephemeral: false,
+            })
+            .await?;
+
+        let profile = options.profile.as_deref();
+
+        get_check_profile(context, interaction.guild_id, profile).await?;
+
+        let channel_id = options.channel;
+
+        if !context
+            .database
+            .remove_channel_rule(interaction.guild_id, profile, channel_id)
+            .await?
+        {
+            return Err(Error::Custom(format!(
+                "<#{channel_id}> does not have any rules."
+            )));
+        }
+
+        let embed = EmbedBuilder::new()
+            .color(0xF8F8FF)
+            .description(format!(
+                "<#{channel_id}> will now be checked like the rest of the server during {}.",
+                get_invite_checks_name(profile)
+            ))
+            .build();
+
+        interaction
+            .context
+            .update_response(UpdateResponsePayload {
+                embeds: vec![embed],
+                ..Default::default()
+            })
+            .await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,88 @@
+use twilight_interactions::command::{CommandModel, CreateCommand};
+
+use super::get_check_profile;
+use crate::{
+    types::{
+        context::Context,
+        interaction::{
+            ApplicationCommandInteraction,
+            DeferInteractionPayload,
+            UpdateResponsePayload,
+        },
+        Result,
+    },
+    utility::{error::Error, message::get_list_embed},
+};
+
+#[derive(CommandModel, CreateCommand)]
+#[command(desc = "List the channels that have rules", name = "list")]
+pub struct ConfigChannelListCommand {
+    #[command(
+        desc = "The check profile whose rules to list, instead of the server's own",
+        max_length = 32
+    )]
+    profile: Option<String>,
+}
+
+impl ConfigChannelListCommand {
+    pub async fn run(
+        context: &Context,
+        interaction: &mut ApplicationCommandInteraction<'_>,
+        options: Self,
+    ) -> Result<()> {
+        interaction
+            .context
+            .defer(DeferInteractionPayload {
+                ephemeral: false,
+            })
+            .await?;
+
+        let Some(database_guild) = context.database.get_guild(interaction.guild_id).await else {
+            return Err(Error::Custom(
+                "Please kick and re-invite Sakura.".to_owned(),
+            ));
+        };
+        let profile = options.profile.as_deref();
+        let embed_color = get_check_profile(context, interaction.guild_id, profile)
+            .await?
+            .map_or(database_guild.embed_color, |check_profile| {
+                check_profile.embed_color
+            });
+        let mut channel_rules = context
+            .database
+            .get_channel_rules(interaction.guild_id, profile)
+            .await?
+            .into_values()
+            .collect::<Vec<_>>();
+
+        if channel_rules.is_empty() {
+            return Err(Error::Custom(
+                "There are no channels with rules.".to_owned(),
+            ));
+        }
+
+        channel_rules.sort_unstable_by_key(|channel_rule| channel_rule.channel_id);
+
+        let lines = channel_rules
+            .iter()
+            .map(|channel_rule| {
+                format!(
+                    "- <#{}> {}",
+                    channel_rule.channel_id,
+                    channel_rule.description()
+                )
+            })
+            .collect();
+        let embed = get_list_embed("Channel rules", lines, embed_color as u32);
+
+        interaction
+            .context
+            .update_response(UpdateResponsePayload {
+                embeds: vec![embed],
+                ..Default::default()
+            })
+            .await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,98 @@
+mod clear;
+mod list;
+mod set_allow_temporary_invites;
+mod set_expected_invites;
+mod set_owner;
+
+use twilight_interactions::command::{CommandModel, CreateCommand};
+use twilight_model::id::{marker::GuildMarker, Id};
+
+use self::{
+    clear::ConfigChannelClearCommand,
+    list::ConfigChannelListCommand,
+    set_allow_temporary_invites::ConfigChannelSetAllowTemporaryInvitesCommand,
+    set_expected_invites::ConfigChannelSetExpectedInvitesCommand,
+    set_owner::ConfigChannelSetOwnerCommand,
+};
+use crate::{
+    types::{
+        context::Context,
+        database::CheckProfile,
+        interaction::ApplicationCommandInteraction,
+        Result,
+    },
+    utility::error::Error,
+};
+
+#[derive(CommandModel, CreateCommand)]
+#[command(desc = "Manage rules for single channels", name = "channel")]
+pub enum ConfigChannelCommand {
+    #[command(name = "clear")]
+    Clear(ConfigChannelClearCommand),
+    #[command(name = "list")]
+    List(ConfigChannelListCommand),
+    #[command(name = "set-allow-temporary-invites")]
+    SetAllowTemporaryInvites(ConfigChannelSetAllowTemporaryInvitesCommand),
+    #[command(name = "set-expected-invites")]
+    SetExpectedInvites(ConfigChannelSetExpectedInvitesCommand),
+    #[command(name = "set-owner")]
+    SetOwner(ConfigChannelSetOwnerCommand),
+}
+
+impl ConfigChannelCommand {
+    pub async fn run(
+        context: &Context,
+        interaction: &mut ApplicationCommandInteraction<'_>,
+        options: Self,
+    ) -> Result<()> {
+        match options {
+            ConfigChannelCommand::Clear(options) => {
+                ConfigChannelClearCommand::run(context, interaction, options).await?
+            }
+            ConfigChannelCommand::List(options) => {
+                ConfigChannelListCommand::run(context, interaction, options).await?
+            }
+            ConfigChannelCommand::SetAllowTemporaryInvites(options) => {
+                ConfigChannelSetAllowTemporaryInvitesCommand::run(context, interaction, options)
+                    .await?
+            }
+            ConfigChannelCommand::SetExpectedInvites(options) => {
+                ConfigChannelSetExpectedInvitesCommand::run(context, interaction, options).await?
+            }
+            ConfigChannelCommand::SetOwner(options) => {
+                ConfigChannelSetOwnerCommand::run(context, interaction, options).await?
+            }
+        }
+
+        Ok(())
+    }
+}
+
+// Rules without a profile belong to the server's own invite checks
+async fn get_check_profile(
+    context: &Context,
+    guild_id: Id<GuildMarker>,
+    profile: Option<&str>,
+) -> Result<Option<CheckProfile>> {
+    let Some(profile) = profile else {
+        return Ok(None);
+    };
+    let Some(check_profile) = context
+        .database
+        .get_check_profile(guild_id, profile)
+        .await?
+    else {
+        return Err(Error::Custom(format!(
+            "There is no check profile named `{profile}`."
+        )));
+    };
+
+    Ok(Some(check_profile))
+}
+
+fn get_invite_checks_name(profile: Option<&str>) -> String {
+    match profile {
+        Some(profile) => format!("`{profile}` invite checks"),
+        None => "invite checks".to_owned(),
+    }
+}
@@ -0,0 +1,108 @@
+use twilight_interactions::command::{CommandModel, CreateCommand};
+use twilight_model::id::{marker::ChannelMarker, Id};
+use twilight_util::builder::embed::EmbedBuilder;
+
+use super::{get_check_profile, get_invite_checks_name};
+use crate::{
+    types::{
+        context::Context,
+        interaction::{
+            ApplicationCommandInteraction,
+            DeferInteractionPayload,
+            UpdateResponsePayload,
+        },
+        Result,
+    },
+    utility::error::Error,
+};
+
+#[derive(CommandModel, CreateCommand)]
+#[command(
+    desc = "Set whether a channel may have temporary invites",
+    name = "set-allow-temporary-invites"
+)]
+pub struct ConfigChannelSetAllowTemporaryInvitesCommand {
+    #[command(
+        channel_types = "guild_news guild_news_thread guild_public_thread guild_text",
+        desc = "The announcement channel, text channel, thread or forum post"
+    )]
+    channel: Id<ChannelMarker>,
+    #[command(desc = "Whether temporary invites are allowed in the channel")]
+    allowed: bool,
+    #[command(
+        desc = "The check profile the rule belongs to, instead of the server's own",
+        max_length = 32
+    )]
+    profile: Option<String>,
+}
+
+impl ConfigChannelSetAllowTemporaryInvitesCommand {
+    pub async fn run(
+        context: &Context,
+        interaction: &mut ApplicationCommandInteraction<'_>,
+        options: Self,
+    ) -> Result<()> {
+        interaction
+            .context
+            .defer(DeferInteractionPayload {
+                ephemeral: false,
+            })
+            .await?;
+
+        let Some(database_guild) = context.database.get_guild(interaction.guild_id).await else {
+            return Err(Error::Custom(
+                "Please kick and re-invite Sakura.".to_owned(),
+            ));
+        };
+        let profile = options.profile.as_deref();
+        let require_permanent_invites = get_check_profile(context, interaction.guild_id, profile)
+            .await?
+            .map_or(database_guild.require_permanent_invites, |check_profile| {
+                check_profile.require_permanent_invites
+            });
+        let channel_id = options.channel;
+
+        context
+            .database
+            .insert_channel_allow_temporary_invites(
+                interaction.guild_id,
+                profile,
+                channel_id,
+                options.allowed,
+            )
+            .await?;
+
+        let mut description = if options.allowed {
+            format!(
+                "Temporary invites in <#{channel_id}> will now count as valid during {}.",
+                get_invite_checks_name(profile)
+            )
+        } else {
+            format!(
+                "Temporary invites in <#{channel_id}> will now be treated like the rest of the server during {}.",
+                get_invite_checks_name(profile)
+            )
+        };
+
+        if !require_permanent_invites {
+            description.push_str(
+                "\n\nPermanent invites are not required at the moment, so this only has an effect once they are.",
+            );
+        }
+
+        let embed = EmbedBuilder::new()
+            .color(0xF8F8FF)
+            .description(description)
+            .build();
+
+        interaction
+            .context
+            .update_response(UpdateResponsePayload {
+                embeds: vec![embed],
+                ..Default::default()
+            })
+            .await?;
+
+        Ok(())
+    }
+}
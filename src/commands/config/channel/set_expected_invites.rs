@@ -0,0 +1,145 @@
+use twilight_interactions::command::{CommandModel, CreateCommand};
+use twilight_model::id::{marker::ChannelMarker, Id};
+use twilight_util::builder::embed::EmbedBuilder;
+
+use super::{get_check_profile, get_invite_checks_name};
+use crate::{
+    types::{
+        context::Context,
+        interaction::{
+            ApplicationCommandInteraction,
+            DeferInteractionPayload,
+            UpdateResponsePayload,
+        },
+        Result,
+    },
+    utility::error::Error,
+};
+
+#[derive(CommandModel, CreateCommand)]
+#[command(
+    desc = "Set how many valid invites a channel is expected to have",
+    name = "set-expected-invites"
+)]
+pub struct ConfigChannelSetExpectedInvitesCommand {
+    #[command(
+        channel_types = "guild_news guild_news_thread guild_public_thread guild_text",
+        desc = "The announcement channel, text channel, thread or forum post"
+    )]
+    channel: Id<ChannelMarker>,
+    #[command(
+        desc = "The minimum number of valid invites (0 for no minimum)",
+        max_value = 1000,
+        min_value = 0
+    )]
+    minimum: i64,
+    #[command(
+        desc = "The maximum number of valid invites",
+        max_value = 1000,
+        min_value = 1
+    )]
+    maximum: Option<i64>,
+    #[command(
+        desc = "The check profile the rule belongs to, instead of the server's own",
+        max_length = 32
+    )]
+    profile: Option<String>,
+}
+
+impl ConfigChannelSetExpectedInvitesCommand {
+    pub async fn run(
+        context: &Context,
+        interaction: &mut ApplicationCommandInteraction<'_>,
+        options: Self,
+    ) -> Result<()> {
+        interaction
+            .context
+            .defer(DeferInteractionPayload {
+                ephemeral: false,
+            })
+            .await?;
+
+        if context
+            .database
+            .get_guild(interaction.guild_id)
+            .await
+            .is_none()
+        {
+            return Err(Error::Custom(
+                "Please kick and re-invite Sakura.".to_owned(),
+            ));
+        }
+
+        let profile = options.profile.as_deref();
+
+        get_check_profile(context, interaction.guild_id, profile).await?;
+
+        let channel_id = options.channel;
+        let invite_checks = get_invite_checks_name(profile);
+        let minimum_invites = (options.minimum > 0).then_some(options.minimum as i32);
+        let maximum_invites = options.maximum.map(|maximum| maximum as i32);
+
+        if let (Some(minimum_invites), Some(maximum_invites)) = (minimum_invites, maximum_invites) {
+            if minimum_invites > maximum_invites {
+                return Err(Error::Custom(
+                    "The minimum cannot be larger than the maximum.".to_owned(),
+                ));
+            }
+        }
+
+        context
+            .database
+            .insert_channel_expected_invites(
+                interaction.guild_id,
+                profile,
+                channel_id,
+                minimum_invites,
+                maximum_invites,
+            )
+            .await?;
+
+        let description = match (minimum_invites, maximum_invites) {
+            (None, None) => {
+                format!(
+                    "<#{channel_id}> no longer expects a number of valid invites during {invite_checks}."
+                )
+            }
+            (Some(minimum_invites), Some(maximum_invites))
+                if minimum_invites == maximum_invites =>
+            {
+                format!(
+                    "<#{channel_id}> will now be flagged during {invite_checks} unless it has exactly **{minimum_invites}** valid invite(s)."
+                )
+            }
+            (Some(minimum_invites), Some(maximum_invites)) => {
+                format!(
+                    "<#{channel_id}> will now be flagged during {invite_checks} unless it has between **{minimum_invites}** and **{maximum_invites}** valid invites."
+                )
+            }
+            (Some(minimum_invites), None) => {
+                format!(
+                    "<#{channel_id}> will now be flagged during {invite_checks} if it has fewer than **{minimum_invites}** valid invite(s)."
+                )
+            }
+            (None, Some(maximum_invites)) => {
+                format!(
+                    "<#{channel_id}> will now be flagged during {invite_checks} if it has more than **{maximum_invites}** valid invite(s)."
+                )
+            }
+        };
+        let embed = EmbedBuilder::new()
+            .color(0xF8F8FF)
+            .description(description)
+            .build();
+
+        interaction
+            .context
+            .update_response(UpdateResponsePayload {
+                embeds: vec![embed],
+                ..Default::default()
+            })
+            .await?;
+
+        Ok(())
+    }
+}
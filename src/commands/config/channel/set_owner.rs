@@ -0,0 +1,103 @@
+use twilight_interactions::command::{CommandModel, CreateCommand};
+use twilight_model::id::{
+    marker::{ChannelMarker, UserMarker},
+    Id,
+};
+use twilight_util::builder::embed::EmbedBuilder;
+
+use super::{get_check_profile, get_invite_checks_name};
+use crate::{
+    types::{
+        context::Context,
+        interaction::{
+            ApplicationCommandInteraction,
+            DeferInteractionPayload,
+            UpdateResponsePayload,
+        },
+        Result,
+    },
+    utility::error::Error,
+};
+
+#[derive(CommandModel, CreateCommand)]
+#[command(desc = "Set the member responsible for a channel", name = "set-owner")]
+pub struct ConfigChannelSetOwnerCommand {
+    #[command(
+        channel_types = "guild_news guild_news_thread guild_public_thread guild_text",
+        desc = "The announcement channel, text channel, thread or forum post"
+    )]
+    channel: Id<ChannelMarker>,
+    #[command(desc = "The member responsible for the channel (leave empty to clear)")]
+    owner: Option<Id<UserMarker>>,
+    #[command(
+        desc = "The check profile the rule belongs to, instead of the server's own",
+        max_length = 32
+    )]
+    profile: Option<String>,
+}
+
+impl ConfigChannelSetOwnerCommand {
+    pub async fn run(
+        context: &Context,
+        interaction: &mut ApplicationCommandInteraction<'_>,
+        options: Self,
+    ) -> Result<()> {
+        interaction
+            .context
+            .defer(DeferInteractionPayload {
+                ephemeral: false,
+            })
+            .await?;
+
+        if context
+            .database
+            .get_guild(interaction.guild_id)
+            .await
+            .is_none()
+        {
+            return Err(Error::Custom(
+                "Please kick and re-invite Sakura.".to_owned(),
+            ));
+        }
+
+        let profile = options.profile.as_deref();
+
+        get_check_profile(context, interaction.guild_id, profile).await?;
+
+        let channel_id = options.channel;
+
+        context
+            .database
+            .insert_channel_owner(interaction.guild_id, profile, channel_id, options.owner)
+            .await?;
+
+        let description = match options.owner {
+            Some(owner_id) => {
+                format!(
+                    "<@{owner_id}> will now be mentioned next to <#{channel_id}> when it needs attention during {}.",
+                    get_invite_checks_name(profile)
+                )
+            }
+            None => {
+                format!(
+                    "<#{channel_id}> no longer has an owner during {}.",
+                    get_invite_checks_name(profile)
+                )
+            }
+        };
+        let embed = EmbedBuilder::new()
+            .color(0xF8F8FF)
+            .description(description)
+            .build();
+
+        interaction
+            .context
+            .update_response(UpdateResponsePayload {
+                embeds: vec![embed],
+                ..Default::default()
+            })
+            .await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,69 @@
+use twilight_interactions::command::{CommandModel, CreateCommand};
+use twilight_util::builder::embed::EmbedBuilder;
+
+use crate::{
+    types::{
+        context::Context,
+        interaction::{
+            ApplicationCommandInteraction,
+            DeferInteractionPayload,
+            UpdateResponsePayload,
+        },
+        Result,
+    },
+    utility::error::Error,
+};
+
+#[derive(CommandModel, CreateCommand)]
+#[command(
+    desc = "Send alerts to the results channel again",
+    name = "clear-alerts-channel"
+)]
+pub struct ConfigClearAlertsChannelCommand {}
+
+impl ConfigClearAlertsChannelCommand {
+    pub async fn run(
+        context: &Context,
+        interaction: &mut ApplicationCommandInteraction<'_>,
+        _options: Self,
+    ) -> Result<()> {
+        interaction
+            .context
+            .defer(DeferInteractionPayload {
+                ephemeral: false,
+            })
+            .await?;
+
+        let Some(database_guild) = context.database.get_guild(interaction.guild_id).await else {
+            return Err(Error::Custom(
+                "Please kick and re-invite Sakura.".to_owned(),
+            ));
+        };
+
+        if database_guild.alerts_channel_id.is_none() {
+            return Err(Error::Custom(
+                "You have not set an alerts channel.".to_owned(),
+            ));
+        }
+
+        context
+            .database
+            .remove_alerts_channel(interaction.guild_id)
+            .await?;
+
+        let embed = EmbedBuilder::new()
+            .color(0xF8F8FF)
+            .description("Alerts will now be sent in your results channel.")
+            .build();
+
+        interaction
+            .context
+            .update_response(UpdateResponsePayload {
+                embeds: vec![embed],
+                ..Default::default()
+            })
+            .await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,71 @@
+use twilight_interactions::command::{CommandModel, CreateCommand};
+use twilight_util::builder::embed::EmbedBuilder;
+
+use crate::{
+    tasks::unschedule_invite_check,
+    types::{
+        context::Context,
+        interaction::{
+            ApplicationCommandInteraction,
+            DeferInteractionPayload,
+            UpdateResponsePayload,
+        },
+        Result,
+    },
+    utility::error::Error,
+};
+
+#[derive(CommandModel, CreateCommand)]
+#[command(
+    desc = "Stop running invite checks on a schedule",
+    name = "clear-schedule"
+)]
+pub struct ConfigClearScheduleCommand {}
+
+impl ConfigClearScheduleCommand {
+    pub async fn run(
+        context: &Context,
+        interaction: &mut ApplicationCommandInteraction<'_>,
+        _options: Self,
+    ) -> Result<()> {
+        interaction
+            .context
+            .defer(DeferInteractionPayload {
+                ephemeral: false,
+            })
+            .await?;
+
+        let Some(database_guild) = context.database.get_guild(interaction.guild_id).await else {
+            return Err(Error::Custom(
+                "Please kick and re-invite Sakura.".to_owned(),
+            ));
+        };
+
+        if database_guild.check_schedule.is_none() {
+            return Err(Error::Custom(
+                "You have not set an invite check schedule.".to_owned(),
+            ));
+        }
+
+        context
+            .database
+            .remove_check_schedule(interaction.guild_id)
+            .await?;
+        unschedule_invite_check(context, interaction.guild_id, None).await?;
+
+        let embed = EmbedBuilder::new()
+            .color(0xF8F8FF)
+            .description("Sakura will no longer run invite checks on a schedule.")
+            .build();
+
+        interaction
+            .context
+            .update_response(UpdateResponsePayload {
+                embeds: vec![embed],
+                ..Default::default()
+            })
+            .await?;
+
+        Ok(())
+    }
+}
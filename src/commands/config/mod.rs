@@ -1,20 +1,56 @@
 mod add_category_channel;
+mod add_ignore_rule;
 mod add_ignored_channel;
+mod add_scan_author;
+mod add_tracked_channel;
+mod channel;
+mod clear_alerts_channel;
+mod clear_schedule;
+mod profile;
 mod remove_category_channel;
+mod remove_ignore_rule;
 mod remove_ignored_channel;
+mod remove_scan_author;
+mod remove_tracked_channel;
+mod rules;
+mod set_alerts_channel;
 mod set_embed_color;
+mod set_expiry_warnings;
+mod set_invalid_invite_alerts;
+mod set_invite_counting_mode;
 mod set_results_channel;
+mod set_scan_depth;
+mod set_schedule;
 mod show;
 
+use std::sync::Arc;
+
 use twilight_interactions::command::{CommandModel, CreateCommand};
 
 use self::{
     add_category_channel::ConfigAddCategoryChannelCommand,
+    add_ignore_rule::ConfigAddIgnoreRuleCommand,
     add_ignored_channel::ConfigAddIgnoredChannelCommand,
+    add_scan_author::ConfigAddScanAuthorCommand,
+    add_tracked_channel::ConfigAddTrackedChannelCommand,
+    channel::ConfigChannelCommand,
+    clear_alerts_channel::ConfigClearAlertsChannelCommand,
+    clear_schedule::ConfigClearScheduleCommand,
+    profile::ConfigProfileCommand,
     remove_category_channel::ConfigRemoveCategoryChannelCommand,
+    remove_ignore_rule::ConfigRemoveIgnoreRuleCommand,
     remove_ignored_channel::ConfigRemoveIgnoredChannelCommand,
+    remove_scan_author::ConfigRemoveScanAuthorCommand,
+    remove_tracked_channel::ConfigRemoveTrackedChannelCommand,
+    rules::ConfigRulesCommand,
+    set_alerts_channel::ConfigSetAlertsChannelCommand,
     set_embed_color::ConfigSetEmbedColorCommand,
+    set_expiry_warnings::ConfigSetExpiryWarningsCommand,
+    set_invalid_invite_alerts::ConfigSetInvalidInviteAlertsCommand,
+    set_invite_counting_mode::ConfigSetInviteCountingModeCommand,
     set_results_channel::ConfigSetResultsChannelCommand,
+    set_scan_depth::ConfigSetScanDepthCommand,
+    set_schedule::ConfigSetScheduleCommand,
     show::ConfigShowCommand,
 };
 use crate::types::{context::Context, interaction::ApplicationCommandInteraction, Result};
@@ -24,44 +60,129 @@ use crate::types::{context::Context, interaction::ApplicationCommandInteraction,
 pub enum ConfigCommand {
     #[command(name = "add-category-channel")]
     AddCategoryChannel(ConfigAddCategoryChannelCommand),
+    #[command(name = "add-ignore-rule")]
+    AddIgnoreRule(ConfigAddIgnoreRuleCommand),
     #[command(name = "add-ignored-channel")]
     AddIgnoredChannel(ConfigAddIgnoredChannelCommand),
+    #[command(name = "add-scan-author")]
+    AddScanAuthor(ConfigAddScanAuthorCommand),
+    #[command(name = "add-tracked-channel")]
+    AddTrackedChannel(ConfigAddTrackedChannelCommand),
+    #[command(name = "channel")]
+    Channel(ConfigChannelCommand),
+    #[command(name = "clear-alerts-channel")]
+    ClearAlertsChannel(ConfigClearAlertsChannelCommand),
+    #[command(name = "clear-schedule")]
+    ClearSchedule(ConfigClearScheduleCommand),
+    #[command(name = "profile")]
+    Profile(ConfigProfileCommand),
     #[command(name = "remove-category-channel")]
     RemoveCategoryChannel(ConfigRemoveCategoryChannelCommand),
+    #[command(name = "remove-ignore-rule")]
+    RemoveIgnoreRule(ConfigRemoveIgnoreRuleCommand),
     #[command(name = "remove-ignored-channel")]
     RemoveIgnoredChannel(ConfigRemoveIgnoredChannelCommand),
+    #[command(name = "remove-scan-author")]
+    RemoveScanAuthor(ConfigRemoveScanAuthorCommand),
+    #[command(name = "remove-tracked-channel")]
+    RemoveTrackedChannel(ConfigRemoveTrackedChannelCommand),
+    #[command(name = "rules")]
+    Rules(ConfigRulesCommand),
+    #[command(name = "set-alerts-channel")]
+    SetAlertsChannel(ConfigSetAlertsChannelCommand),
     #[command(name = "set-embed-color")]
     SetEmbedColor(ConfigSetEmbedColorCommand),
+    #[command(name = "set-expiry-warnings")]
+    SetExpiryWarnings(ConfigSetExpiryWarningsCommand),
+    #[command(name = "set-invalid-invite-alerts")]
+    SetInvalidInviteAlerts(ConfigSetInvalidInviteAlertsCommand),
+    #[command(name = "set-invite-counting-mode")]
+    SetInviteCountingMode(ConfigSetInviteCountingModeCommand),
     #[command(name = "set-results-channel")]
     SetResultsChannel(ConfigSetResultsChannelCommand),
+    #[command(name = "set-scan-depth")]
+    SetScanDepth(ConfigSetScanDepthCommand),
+    #[command(name = "set-schedule")]
+    SetSchedule(ConfigSetScheduleCommand),
     #[command(name = "show")]
     Show(ConfigShowCommand),
 }
 
 impl ConfigCommand {
     pub async fn run(
-        context: &Context,
+        context: &Arc<Context>,
         interaction: &mut ApplicationCommandInteraction<'_>,
     ) -> Result<()> {
         match ConfigCommand::from_interaction(interaction.input_data())? {
             ConfigCommand::AddCategoryChannel(options) => {
                 ConfigAddCategoryChannelCommand::run(context, interaction, options).await?
             }
+            ConfigCommand::AddIgnoreRule(options) => {
+                ConfigAddIgnoreRuleCommand::run(context, interaction, options).await?
+            }
             ConfigCommand::AddIgnoredChannel(options) => {
                 ConfigAddIgnoredChannelCommand::run(context, interaction, options).await?
             }
+            ConfigCommand::AddScanAuthor(options) => {
+                ConfigAddScanAuthorCommand::run(context, interaction, options).await?
+            }
+            ConfigCommand::AddTrackedChannel(options) => {
+                ConfigAddTrackedChannelCommand::run(context, interaction, options).await?
+            }
+            ConfigCommand::Channel(options) => {
+                ConfigChannelCommand::run(context, interaction, options).await?
+            }
+            ConfigCommand::ClearAlertsChannel(options) => {
+                ConfigClearAlertsChannelCommand::run(context, interaction, options).await?
+            }
+            ConfigCommand::ClearSchedule(options) => {
+                ConfigClearScheduleCommand::run(context, interaction, options).await?
+            }
+            ConfigCommand::Profile(options) => {
+                ConfigProfileCommand::run(context, interaction, options).await?
+            }
             ConfigCommand::RemoveCategoryChannel(options) => {
                 ConfigRemoveCategoryChannelCommand::run(context, interaction, options).await?
             }
+            ConfigCommand::RemoveIgnoreRule(options) => {
+                ConfigRemoveIgnoreRuleCommand::run(context, interaction, options).await?
+            }
             ConfigCommand::RemoveIgnoredChannel(options) => {
                 ConfigRemoveIgnoredChannelCommand::run(context, interaction, options).await?
             }
+            ConfigCommand::RemoveScanAuthor(options) => {
+                ConfigRemoveScanAuthorCommand::run(context, interaction, options).await?
+            }
+            ConfigCommand::RemoveTrackedChannel(options) => {
+                ConfigRemoveTrackedChannelCommand::run(context, interaction, options).await?
+            }
+            ConfigCommand::Rules(options) => {
+                ConfigRulesCommand::run(context, interaction, options).await?
+            }
+            ConfigCommand::SetAlertsChannel(options) => {
+                ConfigSetAlertsChannelCommand::run(context, interaction, options).await?
+            }
             ConfigCommand::SetEmbedColor(options) => {
                 ConfigSetEmbedColorCommand::run(context, interaction, options).await?
             }
+            ConfigCommand::SetExpiryWarnings(options) => {
+                ConfigSetExpiryWarningsCommand::run(context, interaction, options).await?
+            }
+            ConfigCommand::SetInvalidInviteAlerts(options) => {
+                ConfigSetInvalidInviteAlertsCommand::run(context, interaction, options).await?
+            }
+            ConfigCommand::SetInviteCountingMode(options) => {
+                ConfigSetInviteCountingModeCommand::run(context, interaction, options).await?
+            }
             ConfigCommand::SetResultsChannel(options) => {
                 ConfigSetResultsChannelCommand::run(context, interaction, options).await?
             }
+            ConfigCommand::SetScanDepth(options) => {
+                ConfigSetScanDepthCommand::run(context, interaction, options).await?
+            }
+            ConfigCommand::SetSchedule(options) => {
+                ConfigSetScheduleCommand::run(context, interaction, options).await?
+            }
             ConfigCommand::Show(options) => {
                 ConfigShowCommand::run(context, interaction, options).await?
             }
@@ -0,0 +1,94 @@
+use twilight_interactions::command::{CommandModel, CreateCommand};
+use twilight_model::id::Id;
+use twilight_util::builder::embed::EmbedBuilder;
+
+use crate::{
+    types::{
+        context::Context,
+        interaction::{
+            ApplicationCommandInteraction,
+            DeferInteractionPayload,
+            UpdateResponsePayload,
+        },
+        Result,
+    },
+    utility::error::Error,
+};
+
+#[derive(CommandModel, CreateCommand)]
+#[command(
+    desc = "Add a server that partner channels of a check profile may not link to",
+    name = "add-blocked-guild"
+)]
+pub struct ConfigProfileAddBlockedGuildCommand {
+    #[command(desc = "The name of the check profile", max_length = 32)]
+    name: String,
+    #[command(desc = "The ID of the server", rename = "guild-id")]
+    guild_id: String,
+}
+
+impl ConfigProfileAddBlockedGuildCommand {
+    pub async fn run(
+        context: &Context,
+        interaction: &mut ApplicationCommandInteraction<'_>,
+        options: Self,
+    ) -> Result<()> {
+        interaction
+            .context
+            .defer(DeferInteractionPayload {
+                ephemeral: false,
+            })
+            .await?;
+
+        let name = options.name;
+        let Some(check_profile) = context
+            .database
+            .get_check_profile(interaction.guild_id, &name)
+            .await?
+        else {
+            return Err(Error::Custom(format!(
+                "There is no check profile named `{name}`."
+            )));
+        };
+        let Some(blocked_guild_id) = options
+            .guild_id
+            .trim()
+            .parse()
+            .ok()
+            .and_then(Id::new_checked)
+        else {
+            return Err(Error::Custom(format!(
+                "**{}** is not a valid server ID.",
+                options.guild_id
+            )));
+        };
+
+        if check_profile.blocked_guild_ids.contains(&blocked_guild_id) {
+            return Err(Error::Custom(format!(
+                "**{blocked_guild_id}** is already a blocked server in the `{name}` check profile."
+            )));
+        }
+
+        context
+            .database
+            .insert_check_profile_blocked_guild(interaction.guild_id, &name, blocked_guild_id)
+            .await?;
+
+        let embed = EmbedBuilder::new()
+            .color(0xF8F8FF)
+            .description(format!(
+                "Invites to **{blocked_guild_id}** will now be flagged during `{name}` invite checks."
+            ))
+            .build();
+
+        interaction
+            .context
+            .update_response(UpdateResponsePayload {
+                embeds: vec![embed],
+                ..Default::default()
+            })
+            .await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,125 @@
+use twilight_interactions::command::{CommandModel, CreateCommand};
+use twilight_model::id::{marker::ChannelMarker, Id};
+use twilight_util::builder::embed::EmbedBuilder;
+
+use crate::{
+    types::{
+        cache::{GuildJobKind, GuildUpdate},
+        context::Context,
+        interaction::{
+            ApplicationCommandInteraction,
+            DeferInteractionPayload,
+            UpdateResponsePayload,
+        },
+        Result,
+    },
+    utility::error::Error,
+};
+
+#[derive(CommandModel, CreateCommand)]
+#[command(
+    desc = "Add a category for Sakura to check with a check profile",
+    name = "add-category-channel"
+)]
+pub struct ConfigProfileAddCategoryChannelCommand {
+    #[command(desc = "The name of the check profile", max_length = 32)]
+    name: String,
+    #[command(channel_types = "guild_category", desc = "The category channel")]
+    channel: Id<ChannelMarker>,
+}
+
+impl ConfigProfileAddCategoryChannelCommand {
+    pub async fn run(
+        context: &Context,
+        interaction: &mut ApplicationCommandInteraction<'_>,
+        options: Self,
+    ) -> Result<()> {
+        interaction
+            .context
+            .defer(DeferInteractionPayload {
+                ephemeral: false,
+            })
+            .await?;
+
+        let (Some(cached_guild), Some(database_guild)) = (
+            context.cache.get_guild(interaction.guild_id),
+            context.database.get_guild(interaction.guild_id).await,
+        ) else {
+            return Err(Error::Custom(
+                "Please kick and re-invite Sakura.".to_owned(),
+            ));
+        };
+        let name = options.name;
+        let Some(check_profile) = context
+            .database
+            .get_check_profile(interaction.guild_id, &name)
+            .await?
+        else {
+            return Err(Error::Custom(format!(
+                "There is no check profile named `{name}`."
+            )));
+        };
+        let category_id = options.channel;
+
+        if check_profile.category_channel_ids.contains(&category_id) {
+            return Err(Error::Custom(format!(
+                "<#{category_id}> is already in the `{name}` check profile."
+            )));
+        }
+
+        // Categories that are already checked elsewhere have their messages stored
+        if !cached_guild
+            .invite_check_category_ids
+            .read()
+            .contains(&category_id)
+            && !cached_guild
+                .check_profile_category_ids
+                .read()
+                .contains(&category_id)
+        {
+            let guild_job = context
+                .cache
+                .lock_guild(interaction.guild_id, GuildJobKind::AddCategoryChannel)?;
+
+            context
+                .scan_category(&database_guild, &guild_job, category_id)
+                .await?;
+            guild_job.ensure_not_cancelled()?;
+        }
+
+        context
+            .database
+            .insert_check_profile_category_channel(interaction.guild_id, &name, category_id)
+            .await?;
+
+        let updated_category_channel_ids = context
+            .database
+            .get_check_profile_category_channel_ids(interaction.guild_id)
+            .await?;
+
+        context.cache.update_guild(
+            interaction.guild_id,
+            GuildUpdate {
+                check_profile_category_ids: Some(updated_category_channel_ids),
+                ..Default::default()
+            },
+        );
+
+        let embed = EmbedBuilder::new()
+            .color(0xF8F8FF)
+            .description(format!(
+                "<#{category_id}> will now be checked during `{name}` invite checks."
+            ))
+            .build();
+
+        interaction
+            .context
+            .update_response(UpdateResponsePayload {
+                embeds: vec![embed],
+                ..Default::default()
+            })
+            .await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,120 @@
+use fancy_regex::Regex;
+use twilight_interactions::command::{CommandModel, CreateCommand};
+use twilight_util::builder::embed::EmbedBuilder;
+
+use crate::{
+    commands::config::add_ignore_rule::{get_ignore_rule, IgnoredChannelType},
+    types::{
+        context::Context,
+        database::IgnoreRule,
+        interaction::{
+            ApplicationCommandInteraction,
+            DeferInteractionPayload,
+            UpdateResponsePayload,
+        },
+        Result,
+    },
+    utility::error::Error,
+};
+
+#[derive(CommandModel, CreateCommand)]
+#[command(
+    desc = "Ignore every channel that matches a rule during invite checks of a check profile",
+    name = "add-ignore-rule"
+)]
+pub struct ConfigProfileAddIgnoreRuleCommand {
+    #[command(desc = "The name of the check profile", max_length = 32)]
+    name: String,
+    #[command(
+        desc = "Ignore channels whose name matches this pattern, such as *rules*",
+        max_length = 100,
+        rename = "name-glob"
+    )]
+    name_glob: Option<String>,
+    #[command(
+        desc = "Ignore channels whose name matches this regular expression",
+        max_length = 100,
+        rename = "name-regex"
+    )]
+    name_regex: Option<String>,
+    #[command(
+        desc = "Ignore channels whose topic contains this keyword",
+        max_length = 100,
+        rename = "topic-keyword"
+    )]
+    topic_keyword: Option<String>,
+    #[command(desc = "Ignore channels of this type", rename = "channel-type")]
+    channel_type: Option<IgnoredChannelType>,
+}
+
+impl ConfigProfileAddIgnoreRuleCommand {
+    pub async fn run(
+        context: &Context,
+        interaction: &mut ApplicationCommandInteraction<'_>,
+        options: Self,
+    ) -> Result<()> {
+        interaction
+            .context
+            .defer(DeferInteractionPayload {
+                ephemeral: false,
+            })
+            .await?;
+
+        let name = options.name;
+        let Some(check_profile) = context
+            .database
+            .get_check_profile(interaction.guild_id, &name)
+            .await?
+        else {
+            return Err(Error::Custom(format!(
+                "There is no check profile named `{name}`."
+            )));
+        };
+        let ignore_rule = get_ignore_rule(
+            options.name_glob,
+            options.name_regex,
+            options.topic_keyword,
+            options.channel_type,
+        )?;
+
+        if let IgnoreRule::NameRegex {
+            pattern,
+        } = &ignore_rule
+        {
+            if Regex::new(pattern).is_err() {
+                return Err(Error::Custom(format!(
+                    "`{pattern}` is not a valid regular expression."
+                )));
+            }
+        }
+
+        if check_profile.ignore_rules.contains(&ignore_rule) {
+            return Err(Error::Custom(format!(
+                "This is already an ignore rule in the `{name}` check profile."
+            )));
+        }
+
+        context
+            .database
+            .insert_check_profile_ignore_rule(interaction.guild_id, &name, &ignore_rule)
+            .await?;
+
+        let embed = EmbedBuilder::new()
+            .color(0xF8F8FF)
+            .description(format!(
+                "{} will now be ignored during `{name}` invite checks.",
+                ignore_rule.description()
+            ))
+            .build();
+
+        interaction
+            .context
+            .update_response(UpdateResponsePayload {
+                embeds: vec![embed],
+                ..Default::default()
+            })
+            .await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,86 @@
+use twilight_interactions::command::{CommandModel, CreateCommand};
+use twilight_model::id::{marker::ChannelMarker, Id};
+use twilight_util::builder::embed::EmbedBuilder;
+
+use crate::{
+    types::{
+        context::Context,
+        interaction::{
+            ApplicationCommandInteraction,
+            DeferInteractionPayload,
+            UpdateResponsePayload,
+        },
+        Result,
+    },
+    utility::error::Error,
+};
+
+#[derive(CommandModel, CreateCommand)]
+#[command(
+    desc = "Add a channel for Sakura to ignore in a check profile",
+    name = "add-ignored-channel"
+)]
+pub struct ConfigProfileAddIgnoredChannelCommand {
+    #[command(desc = "The name of the check profile", max_length = 32)]
+    name: String,
+    #[command(
+        channel_types = "guild_forum guild_news guild_news_thread guild_public_thread guild_text",
+        desc = "The announcement, text or forum channel, thread or forum post"
+    )]
+    channel: Id<ChannelMarker>,
+}
+
+impl ConfigProfileAddIgnoredChannelCommand {
+    pub async fn run(
+        context: &Context,
+        interaction: &mut ApplicationCommandInteraction<'_>,
+        options: Self,
+    ) -> Result<()> {
+        interaction
+            .context
+            .defer(DeferInteractionPayload {
+                ephemeral: false,
+            })
+            .await?;
+
+        let name = options.name;
+        let Some(check_profile) = context
+            .database
+            .get_check_profile(interaction.guild_id, &name)
+            .await?
+        else {
+            return Err(Error::Custom(format!(
+                "There is no check profile named `{name}`."
+            )));
+        };
+        let channel_id = options.channel;
+
+        if check_profile.ignored_channel_ids.contains(&channel_id) {
+            return Err(Error::Custom(format!(
+                "<#{channel_id}> is already ignored in the `{name}` check profile."
+            )));
+        }
+
+        context
+            .database
+            .insert_check_profile_ignored_channel(interaction.guild_id, &name, channel_id)
+            .await?;
+
+        let embed = EmbedBuilder::new()
+            .color(0xF8F8FF)
+            .description(format!(
+                "<#{channel_id}> will now be ignored during `{name}` invite checks."
+            ))
+            .build();
+
+        interaction
+            .context
+            .update_response(UpdateResponsePayload {
+                embeds: vec![embed],
+                ..Default::default()
+            })
+            .await?;
+
+        Ok(())
+    }
+}
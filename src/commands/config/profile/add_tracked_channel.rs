@@ -0,0 +1,160 @@
+use std::time::Duration;
+
+use tokio::time::sleep;
+use twilight_interactions::command::{CommandModel, CreateCommand};
+use twilight_model::id::{marker::ChannelMarker, Id};
+use twilight_util::builder::embed::EmbedBuilder;
+
+use crate::{
+    types::{
+        cache::{GuildJobKind, GuildUpdate},
+        context::Context,
+        interaction::{
+            ApplicationCommandInteraction,
+            DeferInteractionPayload,
+            UpdateResponsePayload,
+        },
+        Result,
+    },
+    utility::error::Error,
+};
+
+#[derive(CommandModel, CreateCommand)]
+#[command(
+    desc = "Add a channel for Sakura to check with a check profile outside of its categories",
+    name = "add-tracked-channel"
+)]
+pub struct ConfigProfileAddTrackedChannelCommand {
+    #[command(desc = "The name of the check profile", max_length = 32)]
+    name: String,
+    #[command(
+        channel_types = "guild_forum guild_news guild_text",
+        desc = "The announcement, text or forum channel"
+    )]
+    channel: Id<ChannelMarker>,
+}
+
+impl ConfigProfileAddTrackedChannelCommand {
+    pub async fn run(
+        context: &Context,
+        interaction: &mut ApplicationCommandInteraction<'_>,
+        options: Self,
+    ) -> Result<()> {
+        interaction
+            .context
+            .defer(DeferInteractionPayload {
+                ephemeral: false,
+            })
+            .await?;
+
+        let Some(database_guild) = context.database.get_guild(interaction.guild_id).await else {
+            return Err(Error::Custom(
+                "Please kick and re-invite Sakura.".to_owned(),
+            ));
+        };
+        let name = options.name;
+        let Some(check_profile) = context
+            .database
+            .get_check_profile(interaction.guild_id, &name)
+            .await?
+        else {
+            return Err(Error::Custom(format!(
+                "There is no check profile named `{name}`."
+            )));
+        };
+        let channel_id = options.channel;
+        let Some(channel) = context.cache.get_channel(channel_id) else {
+            return Err(Error::Custom(
+                "Sakura only tracks announcement, text, forum and media channels.".to_owned(),
+            ));
+        };
+
+        if check_profile.tracked_channel_ids.contains(&channel_id) {
+            return Err(Error::Custom(format!(
+                "<#{channel_id}> is already a tracked channel in the `{name}` check profile."
+            )));
+        }
+
+        if channel
+            .parent_id
+            .is_some_and(|parent_id| check_profile.category_channel_ids.contains(&parent_id))
+        {
+            return Err(Error::Custom(format!(
+                "<#{channel_id}> is already checked as part of a category in the `{name}` check profile."
+            )));
+        }
+
+        if !context.cache.has_minimum_channel_permissions(channel_id) {
+            return Err(Error::Custom(format!("Sakura is unable to check <#{channel_id}>. Please give permission for Sakura to read the channel and add it again.")));
+        }
+
+        // Channels that are already checked elsewhere have their messages stored
+        if context.cache.get_checked_category_id(&channel).is_none() {
+            let guild_job = context
+                .cache
+                .lock_guild(interaction.guild_id, GuildJobKind::AddTrackedChannel)?;
+
+            if channel.is_forum() {
+                context.cache_archived_threads(channel_id).await?;
+            }
+
+            let mut channel_ids_to_process = context
+                .cache
+                .get_thread_ids(interaction.guild_id, channel_id);
+
+            if !channel.is_forum() {
+                channel_ids_to_process.insert(0, channel_id);
+            }
+
+            for channel_id_to_process in channel_ids_to_process {
+                sleep(Duration::from_millis(1000)).await;
+                guild_job.ensure_not_cancelled()?;
+
+                context
+                    .scan_channel(
+                        &database_guild,
+                        channel_id_to_process,
+                        interaction.guild_id.cast(),
+                    )
+                    .await?;
+            }
+
+            guild_job.ensure_not_cancelled()?;
+        }
+
+        context
+            .database
+            .insert_check_profile_tracked_channel(interaction.guild_id, &name, channel_id)
+            .await?;
+
+        let updated_tracked_channel_ids = context
+            .database
+            .get_check_profile_tracked_channel_ids(interaction.guild_id)
+            .await?;
+
+        context.cache.update_guild(
+            interaction.guild_id,
+            GuildUpdate {
+                check_profile_tracked_channel_ids: Some(updated_tracked_channel_ids),
+                ..Default::default()
+            },
+        );
+
+        let embed = EmbedBuilder::new()
+            .color(0xF8F8FF)
+            .description(format!(
+                "<#{channel_id}> will now be checked during `{name}` invite checks."
+            ))
+            .build();
+
+        interaction
+            .context
+            .update_response(UpdateResponsePayload {
+                embeds: vec![embed],
+                ..Default::default()
+            })
+            .await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,81 @@
+use twilight_interactions::command::{CommandModel, CreateCommand};
+use twilight_util::builder::embed::EmbedBuilder;
+
+use crate::{
+    tasks::unschedule_invite_check,
+    types::{
+        context::Context,
+        interaction::{
+            ApplicationCommandInteraction,
+            DeferInteractionPayload,
+            UpdateResponsePayload,
+        },
+        Result,
+    },
+    utility::error::Error,
+};
+
+#[derive(CommandModel, CreateCommand)]
+#[command(
+    desc = "Stop running a check profile's invite checks on a schedule",
+    name = "clear-schedule"
+)]
+pub struct ConfigProfileClearScheduleCommand {
+    #[command(desc = "The name of the check profile", max_length = 32)]
+    name: String,
+}
+
+impl ConfigProfileClearScheduleCommand {
+    pub async fn run(
+        context: &Context,
+        interaction: &mut ApplicationCommandInteraction<'_>,
+        options: Self,
+    ) -> Result<()> {
+        interaction
+            .context
+            .defer(DeferInteractionPayload {
+                ephemeral: false,
+            })
+            .await?;
+
+        let name = options.name;
+        let Some(check_profile) = context
+            .database
+            .get_check_profile(interaction.guild_id, &name)
+            .await?
+        else {
+            return Err(Error::Custom(format!(
+                "There is no check profile named `{name}`."
+            )));
+        };
+
+        if check_profile.check_schedule.is_none() {
+            return Err(Error::Custom(format!(
+                "The `{name}` check profile does not have an invite check schedule."
+            )));
+        }
+
+        context
+            .database
+            .remove_check_profile_schedule(interaction.guild_id, &name)
+            .await?;
+        unschedule_invite_check(context, interaction.guild_id, Some(&name)).await?;
+
+        let embed = EmbedBuilder::new()
+            .color(0xF8F8FF)
+            .description(format!(
+                "Sakura will no longer run `{name}` invite checks on a schedule."
+            ))
+            .build();
+
+        interaction
+            .context
+            .update_response(UpdateResponsePayload {
+                embeds: vec![embed],
+                ..Default::default()
+            })
+            .await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,80 @@
+use twilight_interactions::command::{CommandModel, CreateCommand};
+use twilight_util::builder::embed::EmbedBuilder;
+
+use crate::{
+    types::{
+        context::Context,
+        interaction::{
+            ApplicationCommandInteraction,
+            DeferInteractionPayload,
+            UpdateResponsePayload,
+        },
+        Result,
+    },
+    utility::error::Error,
+};
+
+#[derive(CommandModel, CreateCommand)]
+#[command(
+    desc = "Create a check profile for a separate partner program",
+    name = "create"
+)]
+pub struct ConfigProfileCreateCommand {
+    #[command(desc = "The name of the check profile", max_length = 32)]
+    name: String,
+}
+
+impl ConfigProfileCreateCommand {
+    pub async fn run(
+        context: &Context,
+        interaction: &mut ApplicationCommandInteraction<'_>,
+        options: Self,
+    ) -> Result<()> {
+        interaction
+            .context
+            .defer(DeferInteractionPayload {
+                ephemeral: false,
+            })
+            .await?;
+
+        if context
+            .database
+            .get_guild(interaction.guild_id)
+            .await
+            .is_none()
+        {
+            return Err(Error::Custom(
+                "Please kick and re-invite Sakura.".to_owned(),
+            ));
+        }
+
+        let name = options.name;
+
+        if !context
+            .database
+            .insert_check_profile(interaction.guild_id, &name)
+            .await?
+        {
+            return Err(Error::Custom(format!(
+                "There is already a check profile named `{name}`."
+            )));
+        }
+
+        let embed = EmbedBuilder::new()
+            .color(0xF8F8FF)
+            .description(format!(
+                "The `{name}` check profile has been created. Add categories to it with `/config profile add-category-channel`, then check it with `/check run profile:{name}`."
+            ))
+            .build();
+
+        interaction
+            .context
+            .update_response(UpdateResponsePayload {
+                embeds: vec![embed],
+                ..Default::default()
+            })
+            .await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,88 @@
+use std::sync::Arc;
+
+use twilight_interactions::command::{CommandModel, CreateCommand};
+use twilight_util::builder::embed::EmbedBuilder;
+
+use crate::{
+    tasks::unschedule_invite_check,
+    types::{
+        cache::GuildUpdate,
+        context::Context,
+        interaction::{
+            ApplicationCommandInteraction,
+            DeferInteractionPayload,
+            UpdateResponsePayload,
+        },
+        Result,
+    },
+    utility::error::Error,
+};
+
+#[derive(CommandModel, CreateCommand)]
+#[command(desc = "Delete a check profile", name = "delete")]
+pub struct ConfigProfileDeleteCommand {
+    #[command(desc = "The name of the check profile", max_length = 32)]
+    name: String,
+}
+
+impl ConfigProfileDeleteCommand {
+    pub async fn run(
+        context: &Arc<Context>,
+        interaction: &mut ApplicationCommandInteraction<'_>,
+        options: Self,
+    ) -> Result<()> {
+        interaction
+            .context
+            .defer(DeferInteractionPayload {
+                ephemeral: false,
+            })
+            .await?;
+
+        let name = options.name;
+
+        if !context
+            .database
+            .remove_check_profile(interaction.guild_id, &name)
+            .await?
+        {
+            return Err(Error::Custom(format!(
+                "There is no check profile named `{name}`."
+            )));
+        }
+
+        unschedule_invite_check(context, interaction.guild_id, Some(&name)).await?;
+
+        let updated_category_channel_ids = context
+            .database
+            .get_check_profile_category_channel_ids(interaction.guild_id)
+            .await?;
+        let updated_tracked_channel_ids = context
+            .database
+            .get_check_profile_tracked_channel_ids(interaction.guild_id)
+            .await?;
+
+        context.cache.update_guild(
+            interaction.guild_id,
+            GuildUpdate {
+                check_profile_category_ids: Some(updated_category_channel_ids),
+                check_profile_tracked_channel_ids: Some(updated_tracked_channel_ids),
+                ..Default::default()
+            },
+        );
+
+        let embed = EmbedBuilder::new()
+            .color(0xF8F8FF)
+            .description(format!("The `{name}` check profile has been deleted."))
+            .build();
+
+        interaction
+            .context
+            .update_response(UpdateResponsePayload {
+                embeds: vec![embed],
+                ..Default::default()
+            })
+            .await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,59 @@
+use twilight_interactions::command::{CommandModel, CreateCommand};
+
+use crate::{
+    types::{
+        context::Context,
+        interaction::{
+            ApplicationCommandInteraction,
+            DeferInteractionPayload,
+            UpdateResponsePayload,
+        },
+        Result,
+    },
+    utility::{error::Error, message::get_list_embed},
+};
+
+#[derive(CommandModel, CreateCommand)]
+#[command(desc = "List the check profiles", name = "list")]
+pub struct ConfigProfileListCommand;
+
+impl ConfigProfileListCommand {
+    pub async fn run(
+        context: &Context,
+        interaction: &mut ApplicationCommandInteraction<'_>,
+        _options: Self,
+    ) -> Result<()> {
+        interaction
+            .context
+            .defer(DeferInteractionPayload {
+                ephemeral: false,
+            })
+            .await?;
+
+        let check_profiles = context
+            .database
+            .get_check_profiles(interaction.guild_id)
+            .await?;
+
+        if check_profiles.is_empty() {
+            return Err(Error::Custom(
+                "There are no check profiles. Create one with `/config profile create`.".to_owned(),
+            ));
+        }
+
+        let lines = check_profiles
+            .iter()
+            .map(|check_profile| format!("- {}", check_profile.description()))
+            .collect();
+
+        interaction
+            .context
+            .update_response(UpdateResponsePayload {
+                embeds: vec![get_list_embed("Check profiles", lines, 0xF8F8FF)],
+                ..Default::default()
+            })
+            .await?;
+
+        Ok(())
+    }
+}
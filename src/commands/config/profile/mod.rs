@@ -0,0 +1,156 @@
+mod add_blocked_guild;
+mod add_category_channel;
+mod add_ignore_rule;
+mod add_ignored_channel;
+mod add_tracked_channel;
+mod clear_schedule;
+mod create;
+mod delete;
+mod list;
+mod remove_blocked_guild;
+mod remove_category_channel;
+mod remove_ignore_rule;
+mod remove_ignored_channel;
+mod remove_tracked_channel;
+mod set_embed_color;
+mod set_results_channel;
+mod set_rules;
+mod set_schedule;
+
+use std::sync::Arc;
+
+use twilight_interactions::command::{CommandModel, CreateCommand};
+
+use self::{
+    add_blocked_guild::ConfigProfileAddBlockedGuildCommand,
+    add_category_channel::ConfigProfileAddCategoryChannelCommand,
+    add_ignore_rule::ConfigProfileAddIgnoreRuleCommand,
+    add_ignored_channel::ConfigProfileAddIgnoredChannelCommand,
+    add_tracked_channel::ConfigProfileAddTrackedChannelCommand,
+    clear_schedule::ConfigProfileClearScheduleCommand,
+    create::ConfigProfileCreateCommand,
+    delete::ConfigProfileDeleteCommand,
+    list::ConfigProfileListCommand,
+    remove_blocked_guild::ConfigProfileRemoveBlockedGuildCommand,
+    remove_category_channel::ConfigProfileRemoveCategoryChannelCommand,
+    remove_ignore_rule::ConfigProfileRemoveIgnoreRuleCommand,
+    remove_ignored_channel::ConfigProfileRemoveIgnoredChannelCommand,
+    remove_tracked_channel::ConfigProfileRemoveTrackedChannelCommand,
+    set_embed_color::ConfigProfileSetEmbedColorCommand,
+    set_results_channel::ConfigProfileSetResultsChannelCommand,
+    set_rules::ConfigProfileSetRulesCommand,
+    set_schedule::ConfigProfileSetScheduleCommand,
+};
+use crate::types::{context::Context, interaction::ApplicationCommandInteraction, Result};
+
+#[derive(CommandModel, CreateCommand)]
+#[command(
+    desc = "Manage check profiles for separate partner programs",
+    name = "profile"
+)]
+pub enum ConfigProfileCommand {
+    #[command(name = "add-blocked-guild")]
+    AddBlockedGuild(ConfigProfileAddBlockedGuildCommand),
+    #[command(name = "add-category-channel")]
+    AddCategoryChannel(ConfigProfileAddCategoryChannelCommand),
+    #[command(name = "add-ignore-rule")]
+    AddIgnoreRule(ConfigProfileAddIgnoreRuleCommand),
+    #[command(name = "add-ignored-channel")]
+    AddIgnoredChannel(ConfigProfileAddIgnoredChannelCommand),
+    #[command(name = "add-tracked-channel")]
+    AddTrackedChannel(ConfigProfileAddTrackedChannelCommand),
+    #[command(name = "clear-schedule")]
+    ClearSchedule(ConfigProfileClearScheduleCommand),
+    #[command(name = "create")]
+    Create(ConfigProfileCreateCommand),
+    #[command(name = "delete")]
+    Delete(ConfigProfileDeleteCommand),
+    #[command(name = "list")]
+    List(ConfigProfileListCommand),
+    #[command(name = "remove-blocked-guild")]
+    RemoveBlockedGuild(ConfigProfileRemoveBlockedGuildCommand),
+    #[command(name = "remove-category-channel")]
+    RemoveCategoryChannel(ConfigProfileRemoveCategoryChannelCommand),
+    #[command(name = "remove-ignore-rule")]
+    RemoveIgnoreRule(ConfigProfileRemoveIgnoreRuleCommand),
+    #[command(name = "remove-ignored-channel")]
+    RemoveIgnoredChannel(ConfigProfileRemoveIgnoredChannelCommand),
+    #[command(name = "remove-tracked-channel")]
+    RemoveTrackedChannel(ConfigProfileRemoveTrackedChannelCommand),
+    #[command(name = "set-embed-color")]
+    SetEmbedColor(ConfigProfileSetEmbedColorCommand),
+    #[command(name = "set-results-channel")]
+    SetResultsChannel(ConfigProfileSetResultsChannelCommand),
+    #[command(name = "set-rules")]
+    SetRules(ConfigProfileSetRulesCommand),
+    #[command(name = "set-schedule")]
+    SetSchedule(ConfigProfileSetScheduleCommand),
+}
+
+impl ConfigProfileCommand {
+    pub async fn run(
+        context: &Arc<Context>,
+        interaction: &mut ApplicationCommandInteraction<'_>,
+        options: Self,
+    ) -> Result<()> {
+        match options {
+            ConfigProfileCommand::AddBlockedGuild(options) => {
+                ConfigProfileAddBlockedGuildCommand::run(context, interaction, options).await?
+            }
+            ConfigProfileCommand::AddCategoryChannel(options) => {
+                ConfigProfileAddCategoryChannelCommand::run(context, interaction, options).await?
+            }
+            ConfigProfileCommand::AddIgnoreRule(options) => {
+                ConfigProfileAddIgnoreRuleCommand::run(context, interaction, options).await?
+            }
+            ConfigProfileCommand::AddIgnoredChannel(options) => {
+                ConfigProfileAddIgnoredChannelCommand::run(context, interaction, options).await?
+            }
+            ConfigProfileCommand::AddTrackedChannel(options) => {
+                ConfigProfileAddTrackedChannelCommand::run(context, interaction, options).await?
+            }
+            ConfigProfileCommand::ClearSchedule(options) => {
+                ConfigProfileClearScheduleCommand::run(context, interaction, options).await?
+            }
+            ConfigProfileCommand::Create(options) => {
+                ConfigProfileCreateCommand::run(context, interaction, options).await?
+            }
+            ConfigProfileCommand::Delete(options) => {
+                ConfigProfileDeleteCommand::run(context, interaction, options).await?
+            }
+            ConfigProfileCommand::List(options) => {
+                ConfigProfileListCommand::run(context, interaction, options).await?
+            }
+            ConfigProfileCommand::RemoveBlockedGuild(options) => {
+                ConfigProfileRemoveBlockedGuildCommand::run(context, interaction, options).await?
+            }
+            ConfigProfileCommand::RemoveCategoryChannel(options) => {
+                ConfigProfileRemoveCategoryChannelCommand::run(context, interaction, options)
+                    .await?
+            }
+            ConfigProfileCommand::RemoveIgnoreRule(options) => {
+                ConfigProfileRemoveIgnoreRuleCommand::run(context, interaction, options).await?
+            }
+            ConfigProfileCommand::RemoveIgnoredChannel(options) => {
+                ConfigProfileRemoveIgnoredChannelCommand::run(context, interaction, options).await?
+            }
+            ConfigProfileCommand::RemoveTrackedChannel(options) => {
+                ConfigProfileRemoveTrackedChannelCommand::run(context, interaction, options).await?
+            }
+            ConfigProfileCommand::SetEmbedColor(options) => {
+                ConfigProfileSetEmbedColorCommand::run(context, interaction, options).await?
+            }
+            ConfigProfileCommand::SetResultsChannel(options) => {
+                ConfigProfileSetResultsChannelCommand::run(context, interaction, options).await?
+            }
+            ConfigProfileCommand::SetRules(options) => {
+                ConfigProfileSetRulesCommand::run(context, interaction, options).await?
+            }
+            ConfigProfileCommand::SetSchedule(options) => {
+                ConfigProfileSetScheduleCommand::run(context, interaction, options).await?
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,97 @@
+use twilight_interactions::command::{CommandModel, CreateCommand};
+use twilight_model::id::{marker::ChannelMarker, Id};
+use twilight_util::builder::embed::EmbedBuilder;
+
+use crate::{
+    types::{
+        cache::GuildUpdate,
+        context::Context,
+        interaction::{
+            ApplicationCommandInteraction,
+            DeferInteractionPayload,
+            UpdateResponsePayload,
+        },
+        Result,
+    },
+    utility::error::Error,
+};
+
+#[derive(CommandModel, CreateCommand)]
+#[command(
+    desc = "Remove a category from a check profile",
+    name = "remove-category-channel"
+)]
+pub struct ConfigProfileRemoveCategoryChannelCommand {
+    #[command(desc = "The name of the check profile", max_length = 32)]
+    name: String,
+    #[command(channel_types = "guild_category", desc = "The category channel")]
+    channel: Id<ChannelMarker>,
+}
+
+impl ConfigProfileRemoveCategoryChannelCommand {
+    pub async fn run(
+        context: &Context,
+        interaction: &mut ApplicationCommandInteraction<'_>,
+        options: Self,
+    ) -> Result<()> {
+        interaction
+            .context
+            .defer(DeferInteractionPayload {
+                ephemeral: false,
+            })
+            .await?;
+
+        let name = options.name;
+        let Some(check_profile) = context
+            .database
+            .get_check_profile(interaction.guild_id, &name)
+            .await?
+        else {
+            return Err(Error::Custom(format!(
+                "There is no check profile named `{name}`."
+            )));
+        };
+        let category_id = options.channel;
+
+        if !check_profile.category_channel_ids.contains(&category_id) {
+            return Err(Error::Custom(format!(
+                "<#{category_id}> is not in the `{name}` check profile."
+            )));
+        }
+
+        context
+            .database
+            .remove_check_profile_category_channel(interaction.guild_id, &name, category_id)
+            .await?;
+
+        let updated_category_channel_ids = context
+            .database
+            .get_check_profile_category_channel_ids(interaction.guild_id)
+            .await?;
+
+        context.cache.update_guild(
+            interaction.guild_id,
+            GuildUpdate {
+                check_profile_category_ids: Some(updated_category_channel_ids),
+                ..Default::default()
+            },
+        );
+
+        let embed = EmbedBuilder::new()
+            .color(0xF8F8FF)
+            .description(format!(
+                "<#{category_id}> will no longer be checked during `{name}` invite checks."
+            ))
+            .build();
+
+        interaction
+            .context
+            .update_response(UpdateResponsePayload {
+                embeds: vec![embed],
+                ..Default::default()
+            })
+            .await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,107 @@
+use twilight_interactions::command::{CommandModel, CreateCommand};
+use twilight_util::builder::embed::EmbedBuilder;
+
+use crate::{
+    commands::config::add_ignore_rule::{get_ignore_rule, IgnoredChannelType},
+    types::{
+        context::Context,
+        interaction::{
+            ApplicationCommandInteraction,
+            DeferInteractionPayload,
+            UpdateResponsePayload,
+        },
+        Result,
+    },
+    utility::error::Error,
+};
+
+#[derive(CommandModel, CreateCommand)]
+#[command(
+    desc = "Remove a rule that ignores channels during invite checks of a check profile",
+    name = "remove-ignore-rule"
+)]
+pub struct ConfigProfileRemoveIgnoreRuleCommand {
+    #[command(desc = "The name of the check profile", max_length = 32)]
+    name: String,
+    #[command(
+        desc = "The name pattern of the rule",
+        max_length = 100,
+        rename = "name-glob"
+    )]
+    name_glob: Option<String>,
+    #[command(
+        desc = "The name regular expression of the rule",
+        max_length = 100,
+        rename = "name-regex"
+    )]
+    name_regex: Option<String>,
+    #[command(
+        desc = "The topic keyword of the rule",
+        max_length = 100,
+        rename = "topic-keyword"
+    )]
+    topic_keyword: Option<String>,
+    #[command(desc = "The channel type of the rule", rename = "channel-type")]
+    channel_type: Option<IgnoredChannelType>,
+}
+
+impl ConfigProfileRemoveIgnoreRuleCommand {
+    pub async fn run(
+        context: &Context,
+        interaction: &mut ApplicationCommandInteraction<'_>,
+        options: Self,
+    ) -> Result<()> {
+        interaction
+            .context
+            .defer(DeferInteractionPayload {
+                ephemeral: false,
+            })
+            .await?;
+
+        let name = options.name;
+        let Some(check_profile) = context
+            .database
+            .get_check_profile(interaction.guild_id, &name)
+            .await?
+        else {
+            return Err(Error::Custom(format!(
+                "There is no check profile named `{name}`."
+            )));
+        };
+        let ignore_rule = get_ignore_rule(
+            options.name_glob,
+            options.name_regex,
+            options.topic_keyword,
+            options.channel_type,
+        )?;
+
+        if !check_profile.ignore_rules.contains(&ignore_rule) {
+            return Err(Error::Custom(format!(
+                "This is not an ignore rule in the `{name}` check profile."
+            )));
+        }
+
+        context
+            .database
+            .remove_check_profile_ignore_rule(interaction.guild_id, &name, &ignore_rule)
+            .await?;
+
+        let embed = EmbedBuilder::new()
+            .color(0xF8F8FF)
+            .description(format!(
+                "{} will no longer be ignored during `{name}` invite checks.",
+                ignore_rule.description()
+            ))
+            .build();
+
+        interaction
+            .context
+            .update_response(UpdateResponsePayload {
+                embeds: vec![embed],
+                ..Default::default()
+            })
+            .await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,100 @@
+use twilight_interactions::command::{CommandModel, CreateCommand};
+use twilight_model::id::{marker::ChannelMarker, Id};
+use twilight_util::builder::embed::EmbedBuilder;
+
+use crate::{
+    types::{
+        cache::GuildUpdate,
+        context::Context,
+        interaction::{
+            ApplicationCommandInteraction,
+            DeferInteractionPayload,
+            UpdateResponsePayload,
+        },
+        Result,
+    },
+    utility::error::Error,
+};
+
+#[derive(CommandModel, CreateCommand)]
+#[command(
+    desc = "Remove a channel from the tracked channels of a check profile",
+    name = "remove-tracked-channel"
+)]
+pub struct ConfigProfileRemoveTrackedChannelCommand {
+    #[command(desc = "The name of the check profile", max_length = 32)]
+    name: String,
+    #[command(
+        channel_types = "guild_forum guild_news guild_text",
+        desc = "The announcement, text or forum channel"
+    )]
+    channel: Id<ChannelMarker>,
+}
+
+impl ConfigProfileRemoveTrackedChannelCommand {
+    pub async fn run(
+        context: &Context,
+        interaction: &mut ApplicationCommandInteraction<'_>,
+        options: Self,
+    ) -> Result<()> {
+        interaction
+            .context
+            .defer(DeferInteractionPayload {
+                ephemeral: false,
+            })
+            .await?;
+
+        let name = options.name;
+        let Some(check_profile) = context
+            .database
+            .get_check_profile(interaction.guild_id, &name)
+            .await?
+        else {
+            return Err(Error::Custom(format!(
+                "There is no check profile named `{name}`."
+            )));
+        };
+        let channel_id = options.channel;
+
+        if !check_profile.tracked_channel_ids.contains(&channel_id) {
+            return Err(Error::Custom(format!(
+                "<#{channel_id}> is not a tracked channel in the `{name}` check profile."
+            )));
+        }
+
+        context
+            .database
+            .remove_check_profile_tracked_channel(interaction.guild_id, &name, channel_id)
+            .await?;
+
+        let updated_tracked_channel_ids = context
+            .database
+            .get_check_profile_tracked_channel_ids(interaction.guild_id)
+            .await?;
+
+        context.cache.update_guild(
+            interaction.guild_id,
+            GuildUpdate {
+                check_profile_tracked_channel_ids: Some(updated_tracked_channel_ids),
+                ..Default::default()
+            },
+        );
+
+        let embed = EmbedBuilder::new()
+            .color(0xF8F8FF)
+            .description(format!(
+                "<#{channel_id}> will no longer be checked during `{name}` invite checks."
+            ))
+            .build();
+
+        interaction
+            .context
+            .update_response(UpdateResponsePayload {
+                embeds: vec![embed],
+                ..Default::default()
+            })
+            .await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,93 @@
+use twilight_interactions::command::{CommandModel, CreateCommand};
+use twilight_util::builder::embed::EmbedBuilder;
+
+use crate::{
+    types::{
+        context::Context,
+        interaction::{
+            ApplicationCommandInteraction,
+            DeferInteractionPayload,
+            UpdateResponsePayload,
+        },
+        Result,
+    },
+    utility::error::Error,
+};
+
+#[derive(CommandModel, CreateCommand)]
+#[command(
+    desc = "Set the embed color to use for a check profile's embeds",
+    name = "set-embed-color"
+)]
+pub struct ConfigProfileSetEmbedColorCommand {
+    #[command(desc = "The name of the check profile", max_length = 32)]
+    name: String,
+    #[command(
+        desc = "The (hex) color code (without the leading hashtag)",
+        rename = "hex-code"
+    )]
+    hex_code: String,
+}
+
+impl ConfigProfileSetEmbedColorCommand {
+    pub async fn run(
+        context: &Context,
+        interaction: &mut ApplicationCommandInteraction<'_>,
+        options: Self,
+    ) -> Result<()> {
+        interaction
+            .context
+            .defer(DeferInteractionPayload {
+                ephemeral: false,
+            })
+            .await?;
+
+        let name = options.name;
+        let Some(check_profile) = context
+            .database
+            .get_check_profile(interaction.guild_id, &name)
+            .await?
+        else {
+            return Err(Error::Custom(format!(
+                "There is no check profile named `{name}`."
+            )));
+        };
+        let hex_code = format!("{:0>6}", options.hex_code.to_uppercase());
+
+        if hex_code.chars().any(|char| char.to_digit(16).is_none()) {
+            return Err(Error::Custom(format!(
+                "**#{hex_code}** is not a valid hex code."
+            )));
+        }
+
+        let color = i32::from_str_radix(&hex_code, 16)?;
+
+        if check_profile.embed_color == color {
+            return Err(Error::Custom(format!(
+                "**#{hex_code}** is already the embed color of the `{name}` check profile."
+            )));
+        }
+
+        context
+            .database
+            .insert_check_profile_embed_color(interaction.guild_id, &name, color)
+            .await?;
+
+        let embed = EmbedBuilder::new()
+            .color(0xF8F8FF)
+            .description(format!(
+                "The embed color for `{name}` invite check embeds is now **#{hex_code}**."
+            ))
+            .build();
+
+        interaction
+            .context
+            .update_response(UpdateResponsePayload {
+                embeds: vec![embed],
+                ..Default::default()
+            })
+            .await?;
+
+        Ok(())
+    }
+}
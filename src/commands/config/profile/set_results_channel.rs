@@ -0,0 +1,83 @@
+use twilight_interactions::command::{CommandModel, CreateCommand};
+use twilight_model::id::{marker::ChannelMarker, Id};
+use twilight_util::builder::embed::EmbedBuilder;
+
+use crate::{
+    types::{
+        context::Context,
+        interaction::{
+            ApplicationCommandInteraction,
+            DeferInteractionPayload,
+            UpdateResponsePayload,
+        },
+        Result,
+    },
+    utility::error::Error,
+};
+
+#[derive(CommandModel, CreateCommand)]
+#[command(
+    desc = "Set the channel to send a check profile's results to",
+    name = "set-results-channel"
+)]
+pub struct ConfigProfileSetResultsChannelCommand {
+    #[command(desc = "The name of the check profile", max_length = 32)]
+    name: String,
+    #[command(channel_types = "guild_news guild_text", desc = "The text channel")]
+    channel: Id<ChannelMarker>,
+}
+
+impl ConfigProfileSetResultsChannelCommand {
+    pub async fn run(
+        context: &Context,
+        interaction: &mut ApplicationCommandInteraction<'_>,
+        options: Self,
+    ) -> Result<()> {
+        interaction
+            .context
+            .defer(DeferInteractionPayload {
+                ephemeral: false,
+            })
+            .await?;
+
+        let name = options.name;
+        let Some(check_profile) = context
+            .database
+            .get_check_profile(interaction.guild_id, &name)
+            .await?
+        else {
+            return Err(Error::Custom(format!(
+                "There is no check profile named `{name}`."
+            )));
+        };
+        let channel_id = options.channel;
+
+        if check_profile.results_channel_id == Some(channel_id) {
+            return Err(Error::Custom(format!(
+                "<#{channel_id}> is already the results channel of the `{name}` check profile."
+            )));
+        }
+
+        context
+            .database
+            .insert_check_profile_results_channel(interaction.guild_id, &name, channel_id)
+            .await?;
+
+        let embed = EmbedBuilder::new()
+            .color(0xF8F8FF)
+            .description(format!(
+                "`{name}` invite check results will now be sent in <#{channel_id}>."
+            ))
+            .build();
+
+        interaction
+            .context
+            .update_response(UpdateResponsePayload {
+                embeds: vec![embed],
+                ..Default::default()
+            })
+            .await?;
+
+        Ok(())
+    }
+}
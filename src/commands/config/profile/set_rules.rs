@@ -0,0 +1,146 @@
+use thousands::Separable;
+use twilight_interactions::command::{CommandModel, CreateCommand};
+use twilight_util::builder::embed::EmbedBuilder;
+
+use crate::{
+    types::{
+        context::Context,
+        interaction::{
+            ApplicationCommandInteraction,
+            DeferInteractionPayload,
+            UpdateResponsePayload,
+        },
+        Result,
+    },
+    utility::error::Error,
+};
+
+#[derive(CommandModel, CreateCommand)]
+#[command(
+    desc = "Set the partner rules that a check profile grades against",
+    name = "set-rules"
+)]
+pub struct ConfigProfileSetRulesCommand {
+    #[command(desc = "The name of the check profile", max_length = 32)]
+    name: String,
+    #[command(
+        desc = "The minimum member count (0 to stop checking)",
+        min_value = 0,
+        rename = "minimum-member-count"
+    )]
+    minimum_member_count: Option<i64>,
+    #[command(
+        desc = "Whether a server can be linked in more than one channel",
+        rename = "allow-duplicate-guilds"
+    )]
+    allow_duplicate_guilds: Option<bool>,
+    #[command(
+        desc = "Whether invites have to be permanent",
+        rename = "require-permanent-invites"
+    )]
+    require_permanent_invites: Option<bool>,
+}
+
+impl ConfigProfileSetRulesCommand {
+    pub async fn run(
+        context: &Context,
+        interaction: &mut ApplicationCommandInteraction<'_>,
+        options: Self,
+    ) -> Result<()> {
+        interaction
+            .context
+            .defer(DeferInteractionPayload {
+                ephemeral: false,
+            })
+            .await?;
+
+        if options.minimum_member_count.is_none()
+            && options.allow_duplicate_guilds.is_none()
+            && options.require_permanent_invites.is_none()
+        {
+            return Err(Error::Custom(
+                "Please choose at least one rule to set.".to_owned(),
+            ));
+        }
+
+        let name = options.name;
+        let Some(check_profile) = context
+            .database
+            .get_check_profile(interaction.guild_id, &name)
+            .await?
+        else {
+            return Err(Error::Custom(format!(
+                "There is no check profile named `{name}`."
+            )));
+        };
+        let minimum_member_count = match options.minimum_member_count {
+            Some(count) => {
+                let Ok(count) = i32::try_from(count) else {
+                    return Err(Error::Custom(format!(
+                        "**{}** is too large to be a member count.",
+                        count.separate_with_commas()
+                    )));
+                };
+
+                (count > 0).then_some(count)
+            }
+            None => check_profile.minimum_member_count,
+        };
+        let allow_duplicate_guilds = options
+            .allow_duplicate_guilds
+            .unwrap_or(check_profile.allow_duplicate_guilds);
+        let require_permanent_invites = options
+            .require_permanent_invites
+            .unwrap_or(check_profile.require_permanent_invites);
+
+        context
+            .database
+            .insert_check_profile_rules(
+                interaction.guild_id,
+                &name,
+                minimum_member_count,
+                allow_duplicate_guilds,
+                require_permanent_invites,
+            )
+            .await?;
+
+        let rules = [
+            match minimum_member_count {
+                Some(count) => {
+                    format!(
+                        "- Partner servers need at least **{}** members",
+                        count.separate_with_commas()
+                    )
+                }
+                None => "- Partner servers can have any number of members".to_owned(),
+            },
+            if allow_duplicate_guilds {
+                "- Partner servers can be linked in more than one channel".to_owned()
+            } else {
+                "- Partner servers can only be linked in one channel".to_owned()
+            },
+            if require_permanent_invites {
+                "- Invites have to be permanent".to_owned()
+            } else {
+                "- Invites can be temporary".to_owned()
+            },
+        ];
+        let embed = EmbedBuilder::new()
+            .color(0xF8F8FF)
+            .description(format!(
+                "`{name}` invite checks now use these partner rules:\n{}",
+                rules.join("\n")
+            ))
+            .build();
+
+        interaction
+            .context
+            .update_response(UpdateResponsePayload {
+                embeds: vec![embed],
+                ..Default::default()
+            })
+            .await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,94 @@
+use std::sync::Arc;
+
+use twilight_interactions::command::{CommandModel, CreateCommand};
+use twilight_util::builder::embed::EmbedBuilder;
+
+use crate::{
+    commands::config::set_schedule::get_check_schedule,
+    tasks::schedule_invite_check,
+    types::{
+        context::Context,
+        interaction::{
+            ApplicationCommandInteraction,
+            DeferInteractionPayload,
+            UpdateResponsePayload,
+        },
+        Result,
+    },
+    utility::error::Error,
+};
+
+#[derive(CommandModel, CreateCommand)]
+#[command(
+    desc = "Set a schedule for Sakura to run a check profile's invite checks on",
+    name = "set-schedule"
+)]
+pub struct ConfigProfileSetScheduleCommand {
+    #[command(desc = "The name of the check profile", max_length = 32)]
+    name: String,
+    #[command(desc = "A cron expression in UTC (e.g. \"0 9 * * Mon\" for Mondays at 09:00)")]
+    schedule: String,
+}
+
+impl ConfigProfileSetScheduleCommand {
+    pub async fn run(
+        context: &Arc<Context>,
+        interaction: &mut ApplicationCommandInteraction<'_>,
+        options: Self,
+    ) -> Result<()> {
+        interaction
+            .context
+            .defer(DeferInteractionPayload {
+                ephemeral: false,
+            })
+            .await?;
+
+        let name = options.name;
+        let Some(check_profile) = context
+            .database
+            .get_check_profile(interaction.guild_id, &name)
+            .await?
+        else {
+            return Err(Error::Custom(format!(
+                "There is no check profile named `{name}`."
+            )));
+        };
+        let (check_schedule, next_run) = get_check_schedule(&options.schedule)?;
+
+        if check_profile.check_schedule.as_deref() == Some(check_schedule.as_str()) {
+            return Err(Error::Custom(format!(
+                "`{check_schedule}` is already the invite check schedule of the `{name}` check profile."
+            )));
+        }
+
+        context
+            .database
+            .insert_check_profile_schedule(interaction.guild_id, &name, &check_schedule)
+            .await?;
+        schedule_invite_check(
+            context.clone(),
+            interaction.guild_id,
+            Some(name.clone()),
+            &check_schedule,
+        )
+        .await?;
+
+        let embed = EmbedBuilder::new()
+            .color(0xF8F8FF)
+            .description(format!(
+                "Sakura will now run `{name}` invite checks on the `{check_schedule}` schedule (UTC). The next one will start <t:{}:R>.",
+                next_run.timestamp()
+            ))
+            .build();
+
+        interaction
+            .context
+            .update_response(UpdateResponsePayload {
+                embeds: vec![embed],
+                ..Default::default()
+            })
+            .await?;
+
+        Ok(())
+    }
+}
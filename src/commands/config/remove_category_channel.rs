@@ -64,7 +64,6 @@ impl ConfigRemoveCategoryChannelCommand {
         context.cache.update_guild(
             interaction.guild_id,
             GuildUpdate {
-                in_check: Some(false),
                 invite_check_category_ids: Some(updated_category_channel_ids),
                 ..Default::default()
             },
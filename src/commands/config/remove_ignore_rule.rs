@@ -0,0 +1,98 @@
+use twilight_interactions::command::{CommandModel, CreateCommand};
+use twilight_util::builder::embed::EmbedBuilder;
+
+use super::add_ignore_rule::{get_ignore_rule, IgnoredChannelType};
+use crate::{
+    types::{
+        context::Context,
+        interaction::{
+            ApplicationCommandInteraction,
+            DeferInteractionPayload,
+            UpdateResponsePayload,
+        },
+        Result,
+    },
+    utility::error::Error,
+};
+
+#[derive(CommandModel, CreateCommand)]
+#[command(
+    desc = "Remove a rule that ignores channels during an invite check",
+    name = "remove-ignore-rule"
+)]
+pub struct ConfigRemoveIgnoreRuleCommand {
+    #[command(
+        desc = "The name pattern of the rule",
+        max_length = 100,
+        rename = "name-glob"
+    )]
+    name_glob: Option<String>,
+    #[command(
+        desc = "The name regular expression of the rule",
+        max_length = 100,
+        rename = "name-regex"
+    )]
+    name_regex: Option<String>,
+    #[command(
+        desc = "The topic keyword of the rule",
+        max_length = 100,
+        rename = "topic-keyword"
+    )]
+    topic_keyword: Option<String>,
+    #[command(desc = "The channel type of the rule", rename = "channel-type")]
+    channel_type: Option<IgnoredChannelType>,
+}
+
+impl ConfigRemoveIgnoreRuleCommand {
+    pub async fn run(
+        context: &Context,
+        interaction: &mut ApplicationCommandInteraction<'_>,
+        options: Self,
+    ) -> Result<()> {
+        interaction
+            .context
+            .defer(DeferInteractionPayload {
+                ephemeral: false,
+            })
+            .await?;
+
+        let Some(database_guild) = context.database.get_guild(interaction.guild_id).await else {
+            return Err(Error::Custom(
+                "Please kick and re-invite Sakura.".to_owned(),
+            ));
+        };
+        let ignore_rule = get_ignore_rule(
+            options.name_glob,
+            options.name_regex,
+            options.topic_keyword,
+            options.channel_type,
+        )?;
+
+        if !database_guild.ignore_rules.contains(&ignore_rule) {
+            return Err(Error::Custom("This is not an ignore rule.".to_owned()));
+        }
+
+        context
+            .database
+            .remove_ignore_rule(interaction.guild_id, &ignore_rule)
+            .await?;
+
+        let embed = EmbedBuilder::new()
+            .color(0xF8F8FF)
+            .description(format!(
+                "{} will no longer be ignored during invite checks.",
+                ignore_rule.description()
+            ))
+            .build();
+
+        interaction
+            .context
+            .update_response(UpdateResponsePayload {
+                embeds: vec![embed],
+                ..Default::default()
+            })
+            .await?;
+
+        Ok(())
+    }
+}
@@ -23,8 +23,8 @@ use crate::{
 )]
 pub struct ConfigRemoveIgnoredChannelCommand {
     #[command(
-        channel_types = "guild_news guild_text",
-        desc = "The announcement or text channel"
+        channel_types = "guild_forum guild_news guild_news_thread guild_public_thread guild_text",
+        desc = "The announcement, text or forum channel, thread or forum post"
     )]
     channel: Id<ChannelMarker>,
 }
@@ -45,7 +45,7 @@ impl ConfigRemoveIgnoredChannelCommand {
         let Some(database_guild) = context.database.get_guild(interaction.guild_id).await else {
             return Err(Error::Custom(
                 "Please kick and re-invite Sakura.".to_owned(),
-            ))
+            ));
         };
         let channel_id = options.channel;
 
@@ -63,7 +63,6 @@ impl ConfigRemoveIgnoredChannelCommand {
         context.cache.update_guild(
             interaction.guild_id,
             GuildUpdate {
-                in_check: Some(false),
                 invite_check_category_ids: Some(updated_category_channel_ids),
                 ..Default::default()
             },
@@ -0,0 +1,87 @@
+use twilight_interactions::command::{CommandModel, CreateCommand};
+use twilight_model::id::Id;
+use twilight_util::builder::embed::EmbedBuilder;
+
+use crate::{
+    types::{
+        context::Context,
+        interaction::{
+            ApplicationCommandInteraction,
+            DeferInteractionPayload,
+            UpdateResponsePayload,
+        },
+        Result,
+    },
+    utility::error::Error,
+};
+
+#[derive(CommandModel, CreateCommand)]
+#[command(
+    desc = "Add a server that partner channels may not link to",
+    name = "add-blocked-guild"
+)]
+pub struct ConfigRulesAddBlockedGuildCommand {
+    #[command(desc = "The ID of the server", rename = "guild-id")]
+    guild_id: String,
+}
+
+impl ConfigRulesAddBlockedGuildCommand {
+    pub async fn run(
+        context: &Context,
+        interaction: &mut ApplicationCommandInteraction<'_>,
+        options: Self,
+    ) -> Result<()> {
+        interaction
+            .context
+            .defer(DeferInteractionPayload {
+                ephemeral: false,
+            })
+            .await?;
+
+        let Some(database_guild) = context.database.get_guild(interaction.guild_id).await else {
+            return Err(Error::Custom(
+                "Please kick and re-invite Sakura.".to_owned(),
+            ));
+        };
+        let Some(blocked_guild_id) = options
+            .guild_id
+            .trim()
+            .parse()
+            .ok()
+            .and_then(Id::new_checked)
+        else {
+            return Err(Error::Custom(format!(
+                "**{}** is not a valid server ID.",
+                options.guild_id
+            )));
+        };
+
+        if database_guild.blocked_guild_ids.contains(&blocked_guild_id) {
+            return Err(Error::Custom(format!(
+                "**{blocked_guild_id}** is already a blocked server."
+            )));
+        }
+
+        context
+            .database
+            .insert_blocked_guild(interaction.guild_id, blocked_guild_id)
+            .await?;
+
+        let embed = EmbedBuilder::new()
+            .color(0xF8F8FF)
+            .description(format!(
+                "Invites to **{blocked_guild_id}** will now be flagged during invite checks."
+            ))
+            .build();
+
+        interaction
+            .context
+            .update_response(UpdateResponsePayload {
+                embeds: vec![embed],
+                ..Default::default()
+            })
+            .await?;
+
+        Ok(())
+    }
+}
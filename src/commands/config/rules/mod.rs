@@ -0,0 +1,61 @@
+mod add_blocked_guild;
+mod remove_blocked_guild;
+mod set_allow_duplicate_guilds;
+mod set_minimum_member_count;
+mod set_require_permanent_invites;
+
+use twilight_interactions::command::{CommandModel, CreateCommand};
+
+use self::{
+    add_blocked_guild::ConfigRulesAddBlockedGuildCommand,
+    remove_blocked_guild::ConfigRulesRemoveBlockedGuildCommand,
+    set_allow_duplicate_guilds::ConfigRulesSetAllowDuplicateGuildsCommand,
+    set_minimum_member_count::ConfigRulesSetMinimumMemberCountCommand,
+    set_require_permanent_invites::ConfigRulesSetRequirePermanentInvitesCommand,
+};
+use crate::types::{context::Context, interaction::ApplicationCommandInteraction, Result};
+
+#[derive(CommandModel, CreateCommand)]
+#[command(desc = "Manage rules for partner servers", name = "rules")]
+pub enum ConfigRulesCommand {
+    #[command(name = "add-blocked-guild")]
+    AddBlockedGuild(ConfigRulesAddBlockedGuildCommand),
+    #[command(name = "remove-blocked-guild")]
+    RemoveBlockedGuild(ConfigRulesRemoveBlockedGuildCommand),
+    #[command(name = "set-allow-duplicate-guilds")]
+    SetAllowDuplicateGuilds(ConfigRulesSetAllowDuplicateGuildsCommand),
+    #[command(name = "set-minimum-member-count")]
+    SetMinimumMemberCount(ConfigRulesSetMinimumMemberCountCommand),
+    #[command(name = "set-require-permanent-invites")]
+    SetRequirePermanentInvites(ConfigRulesSetRequirePermanentInvitesCommand),
+}
+
+impl ConfigRulesCommand {
+    pub async fn run(
+        context: &Context,
+        interaction: &mut ApplicationCommandInteraction<'_>,
+        options: Self,
+    ) -> Result<()> {
+        match options {
+            ConfigRulesCommand::AddBlockedGuild(options) => {
+                ConfigRulesAddBlockedGuildCommand::run(context, interaction, options).await?
+            }
+            ConfigRulesCommand::RemoveBlockedGuild(options) => {
+                ConfigRulesRemoveBlockedGuildCommand::run(context, interaction, options).await?
+            }
+            ConfigRulesCommand::SetAllowDuplicateGuilds(options) => {
+                ConfigRulesSetAllowDuplicateGuildsCommand::run(context, interaction, options)
+                    .await?
+            }
+            ConfigRulesCommand::SetMinimumMemberCount(options) => {
+                ConfigRulesSetMinimumMemberCountCommand::run(context, interaction, options).await?
+            }
+            ConfigRulesCommand::SetRequirePermanentInvites(options) => {
+                ConfigRulesSetRequirePermanentInvitesCommand::run(context, interaction, options)
+                    .await?
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,81 @@
+use twilight_interactions::command::{CommandModel, CreateCommand};
+use twilight_util::builder::embed::EmbedBuilder;
+
+use crate::{
+    types::{
+        context::Context,
+        interaction::{
+            ApplicationCommandInteraction,
+            DeferInteractionPayload,
+            UpdateResponsePayload,
+        },
+        Result,
+    },
+    utility::error::Error,
+};
+
+#[derive(CommandModel, CreateCommand)]
+#[command(
+    desc = "Set whether a partner server may be linked in more than one channel",
+    name = "set-allow-duplicate-guilds"
+)]
+pub struct ConfigRulesSetAllowDuplicateGuildsCommand {
+    #[command(desc = "Whether duplicate partner servers are allowed")]
+    allowed: bool,
+}
+
+impl ConfigRulesSetAllowDuplicateGuildsCommand {
+    pub async fn run(
+        context: &Context,
+        interaction: &mut ApplicationCommandInteraction<'_>,
+        options: Self,
+    ) -> Result<()> {
+        interaction
+            .context
+            .defer(DeferInteractionPayload {
+                ephemeral: false,
+            })
+            .await?;
+
+        let Some(database_guild) = context.database.get_guild(interaction.guild_id).await else {
+            return Err(Error::Custom(
+                "Please kick and re-invite Sakura.".to_owned(),
+            ));
+        };
+
+        if database_guild.allow_duplicate_guilds == options.allowed {
+            return Err(Error::Custom(format!(
+                "Duplicate partner servers are already {}.",
+                if options.allowed {
+                    "allowed"
+                } else {
+                    "not allowed"
+                }
+            )));
+        }
+
+        context
+            .database
+            .insert_allow_duplicate_guilds(interaction.guild_id, options.allowed)
+            .await?;
+
+        let embed = EmbedBuilder::new()
+            .color(0xF8F8FF)
+            .description(if options.allowed {
+                "Partner servers may now be linked in more than one channel."
+            } else {
+                "Partner servers linked in more than one channel will now be flagged during invite checks."
+            })
+            .build();
+
+        interaction
+            .context
+            .update_response(UpdateResponsePayload {
+                embeds: vec![embed],
+                ..Default::default()
+            })
+            .await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,89 @@
+use thousands::Separable;
+use twilight_interactions::command::{CommandModel, CreateCommand};
+use twilight_util::builder::embed::EmbedBuilder;
+
+use crate::{
+    types::{
+        context::Context,
+        interaction::{
+            ApplicationCommandInteraction,
+            DeferInteractionPayload,
+            UpdateResponsePayload,
+        },
+        Result,
+    },
+    utility::error::Error,
+};
+
+#[derive(CommandModel, CreateCommand)]
+#[command(
+    desc = "Set the minimum member count for partner servers",
+    name = "set-minimum-member-count"
+)]
+pub struct ConfigRulesSetMinimumMemberCountCommand {
+    #[command(desc = "The minimum member count (0 to stop checking)", min_value = 0)]
+    count: i64,
+}
+
+impl ConfigRulesSetMinimumMemberCountCommand {
+    pub async fn run(
+        context: &Context,
+        interaction: &mut ApplicationCommandInteraction<'_>,
+        options: Self,
+    ) -> Result<()> {
+        interaction
+            .context
+            .defer(DeferInteractionPayload {
+                ephemeral: false,
+            })
+            .await?;
+
+        let Some(database_guild) = context.database.get_guild(interaction.guild_id).await else {
+            return Err(Error::Custom(
+                "Please kick and re-invite Sakura.".to_owned(),
+            ));
+        };
+        let Ok(count) = i32::try_from(options.count) else {
+            return Err(Error::Custom(format!(
+                "**{}** is too large to be a member count.",
+                options.count.separate_with_commas()
+            )));
+        };
+        let minimum_member_count = (count > 0).then_some(count);
+
+        if database_guild.minimum_member_count == minimum_member_count {
+            return Err(Error::Custom(
+                "This is already your minimum member count.".to_owned(),
+            ));
+        }
+
+        context
+            .database
+            .insert_minimum_member_count(interaction.guild_id, minimum_member_count)
+            .await?;
+
+        let description = match minimum_member_count {
+            None => "Sakura will no longer check the member count of partner servers.".to_owned(),
+            Some(count) => {
+                format!(
+                    "Partner servers with fewer than **{}** members will now be flagged during invite checks.",
+                    count.separate_with_commas()
+                )
+            }
+        };
+        let embed = EmbedBuilder::new()
+            .color(0xF8F8FF)
+            .description(description)
+            .build();
+
+        interaction
+            .context
+            .update_response(UpdateResponsePayload {
+                embeds: vec![embed],
+                ..Default::default()
+            })
+            .await?;
+
+        Ok(())
+    }
+}
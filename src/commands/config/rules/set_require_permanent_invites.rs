@@ -0,0 +1,81 @@
+use twilight_interactions::command::{CommandModel, CreateCommand};
+use twilight_util::builder::embed::EmbedBuilder;
+
+use crate::{
+    types::{
+        context::Context,
+        interaction::{
+            ApplicationCommandInteraction,
+            DeferInteractionPayload,
+            UpdateResponsePayload,
+        },
+        Result,
+    },
+    utility::error::Error,
+};
+
+#[derive(CommandModel, CreateCommand)]
+#[command(
+    desc = "Set whether partner invites must never expire",
+    name = "set-require-permanent-invites"
+)]
+pub struct ConfigRulesSetRequirePermanentInvitesCommand {
+    #[command(desc = "Whether permanent invites are required")]
+    required: bool,
+}
+
+impl ConfigRulesSetRequirePermanentInvitesCommand {
+    pub async fn run(
+        context: &Context,
+        interaction: &mut ApplicationCommandInteraction<'_>,
+        options: Self,
+    ) -> Result<()> {
+        interaction
+            .context
+            .defer(DeferInteractionPayload {
+                ephemeral: false,
+            })
+            .await?;
+
+        let Some(database_guild) = context.database.get_guild(interaction.guild_id).await else {
+            return Err(Error::Custom(
+                "Please kick and re-invite Sakura.".to_owned(),
+            ));
+        };
+
+        if database_guild.require_permanent_invites == options.required {
+            return Err(Error::Custom(format!(
+                "Permanent invites are already {}.",
+                if options.required {
+                    "required"
+                } else {
+                    "not required"
+                }
+            )));
+        }
+
+        context
+            .database
+            .insert_require_permanent_invites(interaction.guild_id, options.required)
+            .await?;
+
+        let embed = EmbedBuilder::new()
+            .color(0xF8F8FF)
+            .description(if options.required {
+                "Expiring and limited-use invites will now be reported as temporary during invite checks."
+            } else {
+                "Expiring and limited-use invites will now be reported as valid during invite checks."
+            })
+            .build();
+
+        interaction
+            .context
+            .update_response(UpdateResponsePayload {
+                embeds: vec![embed],
+                ..Default::default()
+            })
+            .await?;
+
+        Ok(())
+    }
+}
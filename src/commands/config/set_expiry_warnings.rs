@@ -0,0 +1,90 @@
+use twilight_interactions::command::{CommandModel, CreateCommand};
+use twilight_util::builder::embed::EmbedBuilder;
+
+use crate::{
+    types::{
+        context::Context,
+        interaction::{
+            ApplicationCommandInteraction,
+            DeferInteractionPayload,
+            UpdateResponsePayload,
+        },
+        Result,
+    },
+    utility::error::Error,
+};
+
+#[derive(CommandModel, CreateCommand)]
+#[command(
+    desc = "Warn daily about invites that are about to expire",
+    name = "set-expiry-warnings"
+)]
+pub struct ConfigSetExpiryWarningsCommand {
+    #[command(
+        desc = "How many days ahead to warn about (0 to stop warning)",
+        max_value = 30,
+        min_value = 0
+    )]
+    days: i64,
+}
+
+impl ConfigSetExpiryWarningsCommand {
+    pub async fn run(
+        context: &Context,
+        interaction: &mut ApplicationCommandInteraction<'_>,
+        options: Self,
+    ) -> Result<()> {
+        interaction
+            .context
+            .defer(DeferInteractionPayload {
+                ephemeral: false,
+            })
+            .await?;
+
+        let Some(database_guild) = context.database.get_guild(interaction.guild_id).await else {
+            return Err(Error::Custom(
+                "Please kick and re-invite Sakura.".to_owned(),
+            ));
+        };
+        let expiry_warning_days = (options.days > 0).then_some(options.days as i32);
+
+        if database_guild.expiry_warning_days == expiry_warning_days {
+            return Err(Error::Custom(
+                "This is already your expiry warning setting.".to_owned(),
+            ));
+        }
+
+        context
+            .database
+            .insert_expiry_warning_days(interaction.guild_id, expiry_warning_days)
+            .await?;
+
+        let description = match expiry_warning_days {
+            None => "Sakura will no longer warn about invites that are about to expire.".to_owned(),
+            Some(days) => {
+                format!(
+                    "Sakura will now warn daily at 12:00 (UTC) about invites that expire within **{days}** day(s).{}",
+                    if database_guild.get_alerts_channel_id().is_none() {
+                        " Please set a results or alerts channel to receive the warnings."
+                    } else {
+                        ""
+                    }
+                )
+            }
+        };
+        let embed = EmbedBuilder::new()
+            .color(0xF8F8FF)
+            .description(description)
+            .build();
+
+        interaction
+            .context
+            .update_response(UpdateResponsePayload {
+                embeds: vec![embed],
+                ..Default::default()
+            })
+            .await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,88 @@
+use twilight_interactions::command::{CommandModel, CreateCommand};
+use twilight_util::builder::embed::EmbedBuilder;
+
+use crate::{
+    types::{
+        context::Context,
+        interaction::{
+            ApplicationCommandInteraction,
+            DeferInteractionPayload,
+            UpdateResponsePayload,
+        },
+        Result,
+    },
+    utility::error::Error,
+};
+
+#[derive(CommandModel, CreateCommand)]
+#[command(
+    desc = "Set whether to send alerts as invites turn invalid",
+    name = "set-invalid-invite-alerts"
+)]
+pub struct ConfigSetInvalidInviteAlertsCommand {
+    #[command(desc = "Whether invalid invite alerts are enabled")]
+    enabled: bool,
+}
+
+impl ConfigSetInvalidInviteAlertsCommand {
+    pub async fn run(
+        context: &Context,
+        interaction: &mut ApplicationCommandInteraction<'_>,
+        options: Self,
+    ) -> Result<()> {
+        interaction
+            .context
+            .defer(DeferInteractionPayload {
+                ephemeral: false,
+            })
+            .await?;
+
+        let Some(database_guild) = context.database.get_guild(interaction.guild_id).await else {
+            return Err(Error::Custom(
+                "Please kick and re-invite Sakura.".to_owned(),
+            ));
+        };
+
+        if database_guild.invalid_invite_alerts == options.enabled {
+            return Err(Error::Custom(format!(
+                "Invalid invite alerts are already {}.",
+                if options.enabled {
+                    "enabled"
+                } else {
+                    "disabled"
+                }
+            )));
+        }
+
+        context
+            .database
+            .insert_invalid_invite_alerts(interaction.guild_id, options.enabled)
+            .await?;
+
+        let embed = EmbedBuilder::new()
+            .color(0xF8F8FF)
+            .description(if options.enabled {
+                format!(
+                    "Sakura will now send alerts as invites in your categories turn invalid.{}",
+                    if database_guild.get_alerts_channel_id().is_none() {
+                        " Please set a results or alerts channel to receive the alerts."
+                    } else {
+                        ""
+                    }
+                )
+            } else {
+                "Sakura will no longer send alerts as invites turn invalid.".to_owned()
+            })
+            .build();
+
+        interaction
+            .context
+            .update_response(UpdateResponsePayload {
+                embeds: vec![embed],
+                ..Default::default()
+            })
+            .await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,95 @@
+use twilight_interactions::command::{CommandModel, CommandOption, CreateCommand, CreateOption};
+use twilight_util::builder::embed::EmbedBuilder;
+
+use crate::{
+    types::{
+        context::Context,
+        database::InviteCountingMode,
+        interaction::{
+            ApplicationCommandInteraction,
+            DeferInteractionPayload,
+            UpdateResponsePayload,
+        },
+        Result,
+    },
+    utility::error::Error,
+};
+
+#[derive(CommandOption, CreateOption)]
+enum Mode {
+    #[option(name = "All messages", value = "all")]
+    All,
+    #[option(name = "First message only", value = "first")]
+    First,
+    #[option(name = "Latest message only", value = "latest")]
+    Latest,
+    #[option(name = "Pinned messages only", value = "pinned")]
+    Pinned,
+}
+
+#[derive(CommandModel, CreateCommand)]
+#[command(
+    desc = "Set which messages in a channel count towards its invites",
+    name = "set-invite-counting-mode"
+)]
+pub struct ConfigSetInviteCountingModeCommand {
+    #[command(desc = "The messages to count invites from")]
+    mode: Mode,
+}
+
+impl ConfigSetInviteCountingModeCommand {
+    pub async fn run(
+        context: &Context,
+        interaction: &mut ApplicationCommandInteraction<'_>,
+        options: Self,
+    ) -> Result<()> {
+        interaction
+            .context
+            .defer(DeferInteractionPayload {
+                ephemeral: false,
+            })
+            .await?;
+
+        let Some(database_guild) = context.database.get_guild(interaction.guild_id).await else {
+            return Err(Error::Custom(
+                "Please kick and re-invite Sakura.".to_owned(),
+            ));
+        };
+        let invite_counting_mode = match options.mode {
+            Mode::All => InviteCountingMode::All,
+            Mode::First => InviteCountingMode::First,
+            Mode::Latest => InviteCountingMode::Latest,
+            Mode::Pinned => InviteCountingMode::Pinned,
+        };
+
+        if database_guild.invite_counting_mode == invite_counting_mode {
+            return Err(Error::Custom(format!(
+                "Sakura already counts invites from the {} in each channel.",
+                invite_counting_mode.label()
+            )));
+        }
+
+        context
+            .database
+            .insert_invite_counting_mode(interaction.guild_id, invite_counting_mode)
+            .await?;
+
+        let embed = EmbedBuilder::new()
+            .color(0xF8F8FF)
+            .description(format!(
+                "Sakura will now count invites from the {} in each channel.",
+                invite_counting_mode.label()
+            ))
+            .build();
+
+        interaction
+            .context
+            .update_response(UpdateResponsePayload {
+                embeds: vec![embed],
+                ..Default::default()
+            })
+            .await?;
+
+        Ok(())
+    }
+}
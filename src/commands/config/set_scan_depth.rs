@@ -0,0 +1,82 @@
+use thousands::Separable;
+use twilight_interactions::command::{CommandModel, CreateCommand};
+use twilight_util::builder::embed::EmbedBuilder;
+
+use crate::{
+    types::{
+        context::Context,
+        interaction::{
+            ApplicationCommandInteraction,
+            DeferInteractionPayload,
+            UpdateResponsePayload,
+        },
+        Result,
+    },
+    utility::error::Error,
+};
+
+#[derive(CommandModel, CreateCommand)]
+#[command(
+    desc = "Set how many messages to scan in each channel",
+    name = "set-scan-depth"
+)]
+pub struct ConfigSetScanDepthCommand {
+    #[command(
+        desc = "The number of most recent messages to scan",
+        max_value = 1000,
+        min_value = 1
+    )]
+    depth: i64,
+}
+
+impl ConfigSetScanDepthCommand {
+    pub async fn run(
+        context: &Context,
+        interaction: &mut ApplicationCommandInteraction<'_>,
+        options: Self,
+    ) -> Result<()> {
+        interaction
+            .context
+            .defer(DeferInteractionPayload {
+                ephemeral: false,
+            })
+            .await?;
+
+        let Some(database_guild) = context.database.get_guild(interaction.guild_id).await else {
+            return Err(Error::Custom(
+                "Please kick and re-invite Sakura.".to_owned(),
+            ));
+        };
+        let scan_depth = options.depth as i32;
+
+        if database_guild.scan_depth == scan_depth {
+            return Err(Error::Custom(format!(
+                "Sakura already scans the last **{scan_depth}** messages in each channel."
+            )));
+        }
+
+        context
+            .database
+            .insert_scan_depth(interaction.guild_id, scan_depth)
+            .await?;
+
+        let description = format!(
+            "Sakura will now scan the last **{}** messages in each channel. Add the category again to rescan it.",
+            scan_depth.separate_with_commas()
+        );
+        let embed = EmbedBuilder::new()
+            .color(0xF8F8FF)
+            .description(description)
+            .build();
+
+        interaction
+            .context
+            .update_response(UpdateResponsePayload {
+                embeds: vec![embed],
+                ..Default::default()
+            })
+            .await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,185 @@
+use std::{str::FromStr, sync::Arc};
+
+use chrono::{DateTime, TimeDelta, Utc};
+use cron::Schedule;
+use twilight_interactions::command::{CommandModel, CreateCommand};
+use twilight_util::builder::embed::EmbedBuilder;
+
+use crate::{
+    tasks::schedule_invite_check,
+    types::{
+        context::Context,
+        interaction::{
+            ApplicationCommandInteraction,
+            DeferInteractionPayload,
+            UpdateResponsePayload,
+        },
+        Result,
+    },
+    utility::{error::Error, time::get_cron_expression},
+};
+
+#[derive(CommandModel, CreateCommand)]
+#[command(
+    desc = "Set a schedule for Sakura to run invite checks on",
+    name = "set-schedule"
+)]
+pub struct ConfigSetScheduleCommand {
+    #[command(desc = "A cron expression in UTC (e.g. \"0 9 * * Mon\" for Mondays at 09:00)")]
+    schedule: String,
+}
+
+impl ConfigSetScheduleCommand {
+    pub async fn run(
+        context: &Arc<Context>,
+        interaction: &mut ApplicationCommandInteraction<'_>,
+        options: Self,
+    ) -> Result<()> {
+        interaction
+            .context
+            .defer(DeferInteractionPayload {
+                ephemeral: false,
+            })
+            .await?;
+
+        let Some(database_guild) = context.database.get_guild(interaction.guild_id).await else {
+            return Err(Error::Custom(
+                "Please kick and re-invite Sakura.".to_owned(),
+            ));
+        };
+        let (check_schedule, next_run) = get_check_schedule(&options.schedule)?;
+
+        if database_guild.check_schedule.as_deref() == Some(check_schedule.as_str()) {
+            return Err(Error::Custom(format!(
+                "`{check_schedule}` is already your invite check schedule."
+            )));
+        }
+
+        context
+            .database
+            .insert_check_schedule(interaction.guild_id, &check_schedule)
+            .await?;
+        schedule_invite_check(context.clone(), interaction.guild_id, None, &check_schedule).await?;
+
+        let embed = EmbedBuilder::new()
+            .color(0xF8F8FF)
+            .description(format!(
+                "Sakura will now run invite checks on the `{check_schedule}` schedule (UTC). The next one will start <t:{}:R>.",
+                next_run.timestamp()
+            ))
+            .build();
+
+        interaction
+            .context
+            .update_response(UpdateResponsePayload {
+                embeds: vec![embed],
+                ..Default::default()
+            })
+            .await?;
+
+        Ok(())
+    }
+}
+
+// Returns the normalized schedule and when it will run next
+pub fn get_check_schedule(schedule: &str) -> Result<(String, DateTime<Utc>)> {
+    let check_schedule = schedule.split_whitespace().collect::<Vec<&str>>().join(" ");
+
+    if check_schedule.split(' ').count() != 5 {
+        return Err(Error::Custom(
+            "Please use a cron expression with five fields (minute, hour, day of month, month, and day of week)."
+                .to_owned(),
+        ));
+    }
+
+    let Ok(schedule) = Schedule::from_str(&get_cron_expression(&check_schedule)) else {
+        return Err(Error::Custom(format!(
+            "`{check_schedule}` is not a valid cron expression."
+        )));
+    };
+    let upcoming_runs = schedule
+        .upcoming(Utc)
+        .take(25)
+        .collect::<Vec<DateTime<Utc>>>();
+    let Some(next_run) = upcoming_runs.first().copied() else {
+        return Err(Error::Custom(format!(
+            "`{check_schedule}` will never run an invite check."
+        )));
+    };
+
+    if upcoming_runs
+        .windows(2)
+        .any(|runs| runs[1] - runs[0] < TimeDelta::hours(1))
+    {
+        return Err(Error::Custom(
+            "Scheduled invite checks must be at least an hour apart.".to_owned(),
+        ));
+    }
+
+    Ok((check_schedule, next_run))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{collections::HashSet, str::FromStr};
+
+    use chrono::{Datelike, Utc, Weekday};
+    use cron::Schedule;
+
+    use super::get_check_schedule;
+    use crate::utility::time::get_cron_expression;
+
+    fn get_weekdays(check_schedule: &str) -> HashSet<Weekday> {
+        Schedule::from_str(&get_cron_expression(check_schedule))
+            .unwrap()
+            .upcoming(Utc)
+            .take(14)
+            .map(|run| run.weekday())
+            .collect()
+    }
+
+    #[test]
+    fn numbers_weekdays_from_sunday() {
+        let (check_schedule, next_run) = get_check_schedule("0  9 * *   1").unwrap();
+
+        assert_eq!(check_schedule, "0 9 * * 1");
+        assert_eq!(next_run.weekday(), Weekday::Mon);
+        assert_eq!(
+            get_check_schedule("0 9 * * 0").unwrap().1.weekday(),
+            Weekday::Sun
+        );
+        assert_eq!(
+            get_check_schedule("0 9 * * 7").unwrap().1.weekday(),
+            Weekday::Sun
+        );
+        assert_eq!(
+            get_check_schedule("0 9 * * Mon").unwrap().1.weekday(),
+            Weekday::Mon
+        );
+    }
+
+    #[test]
+    fn translates_weekday_ranges_and_lists() {
+        use Weekday::*;
+
+        assert_eq!(
+            get_weekdays("0 9 * * 1-5"),
+            HashSet::from([Mon, Tue, Wed, Thu, Fri])
+        );
+        assert_eq!(get_weekdays("0 9 * * 5-7"), HashSet::from([Fri, Sat, Sun]));
+        assert_eq!(get_weekdays("0 9 * * 5-7/2"), HashSet::from([Fri, Sun]));
+        assert_eq!(get_weekdays("0 9 * * 0,3"), HashSet::from([Sun, Wed]));
+        assert_eq!(
+            get_weekdays("0 9 * * */2"),
+            HashSet::from([Sun, Tue, Thu, Sat])
+        );
+    }
+
+    #[test]
+    fn rejects_invalid_schedules() {
+        assert!(get_check_schedule("0 9 * *").is_err());
+        assert!(get_check_schedule("0 9 * * Someday").is_err());
+        assert!(get_check_schedule("*/30 * * * *").is_err());
+        assert!(get_check_schedule("0 9 31 2 *").is_err());
+    }
+}
@@ -1,3 +1,4 @@
+use thousands::Separable;
 use twilight_interactions::command::{CommandModel, CreateCommand};
 use twilight_util::builder::embed::{EmbedBuilder, EmbedFieldBuilder};
 
@@ -36,6 +37,11 @@ impl ConfigShowCommand {
                 "Please kick and re-invite Sakura.".to_owned(),
             ))
         };
+        let alerts_text = database_guild
+            .alerts_channel_id
+            .map_or("Same as results channel.".to_string(), |channel_id| {
+                format!("<#{channel_id}>")
+            });
         let category_channel_ids_text = if database_guild.category_channel_ids.is_empty() {
             "No categories added.".to_string()
         } else {
@@ -54,7 +60,19 @@ impl ConfigShowCommand {
                 .join("\n")
         };
         let color_text = format!("#{:06X}", database_guild.embed_color);
-        let ignored_channel_ids_text = if database_guild.ignored_channel_ids.is_empty() {
+        let expiry_warnings_text = database_guild
+            .expiry_warning_days
+            .map_or("No expiry warnings.".to_string(), |days| {
+                format!("Daily for invites expiring within **{days}** day(s)")
+            });
+        let invalid_invite_alerts_text = if database_guild.invalid_invite_alerts {
+            "Enabled.".to_string()
+        } else {
+            "Disabled.".to_string()
+        };
+        let ignored_channel_ids_text = if database_guild.ignored_channel_ids.is_empty()
+            && database_guild.ignore_rules.is_empty()
+        {
             "No channels ignored.".to_string()
         } else {
             database_guild
@@ -68,6 +86,114 @@ impl ConfigShowCommand {
                             format!("- <#{channel_id}>")
                         })
                 })
+                .chain(
+                    database_guild
+                        .ignore_rules
+                        .iter()
+                        .map(|ignore_rule| format!("- {}", ignore_rule.description())),
+                )
+                .collect::<Vec<String>>()
+                .join("\n")
+        };
+        let mut rule_lines = vec![
+            database_guild.minimum_member_count.map_or(
+                "- No minimum member count".to_string(),
+                |minimum_member_count| {
+                    format!(
+                        "- At least **{}** members",
+                        minimum_member_count.separate_with_commas()
+                    )
+                },
+            ),
+            if database_guild.allow_duplicate_guilds {
+                "- Duplicate servers allowed".to_string()
+            } else {
+                "- Duplicate servers not allowed".to_string()
+            },
+            if database_guild.require_permanent_invites {
+                "- Permanent invites required".to_string()
+            } else {
+                "- Temporary invites allowed".to_string()
+            },
+        ];
+
+        if !database_guild.blocked_guild_ids.is_empty() {
+            let mut blocked_guild_ids = database_guild
+                .blocked_guild_ids
+                .iter()
+                .map(|guild_id| guild_id.get())
+                .collect::<Vec<u64>>();
+
+            blocked_guild_ids.sort_unstable();
+            rule_lines.push(format!(
+                "- Blocked servers: {}",
+                blocked_guild_ids
+                    .iter()
+                    .map(|guild_id| format!("`{guild_id}`"))
+                    .collect::<Vec<String>>()
+                    .join(", ")
+            ));
+        }
+
+        let invite_counting_mode_text = format!(
+            "Invites in the {} of each channel",
+            database_guild.invite_counting_mode.label()
+        );
+        let scan_text = if database_guild.scan_author_ids.is_empty() {
+            format!(
+                "Last **{}** messages from anyone",
+                database_guild.scan_depth.separate_with_commas()
+            )
+        } else {
+            let mut scan_author_ids = database_guild
+                .scan_author_ids
+                .iter()
+                .map(|author_id| author_id.get())
+                .collect::<Vec<u64>>();
+
+            scan_author_ids.sort_unstable();
+            format!(
+                "Last **{}** messages from {}",
+                database_guild.scan_depth.separate_with_commas(),
+                scan_author_ids
+                    .iter()
+                    .map(|author_id| format!("`{author_id}`"))
+                    .collect::<Vec<String>>()
+                    .join(", ")
+            )
+        };
+        let schedule_text = database_guild
+            .check_schedule
+            .map_or("No schedule set.".to_string(), |check_schedule| {
+                format!("`{check_schedule}` (UTC)")
+            });
+        let tracked_channel_ids_text = if database_guild.tracked_channel_ids.is_empty() {
+            "No tracked channels added.".to_string()
+        } else {
+            database_guild
+                .tracked_channel_ids
+                .iter()
+                .map(|channel_id| {
+                    context
+                        .cache
+                        .get_channel(*channel_id)
+                        .map_or(format!("- {channel_id} **(no longer exists)**"), |_| {
+                            format!("- <#{channel_id}>")
+                        })
+                })
+                .collect::<Vec<String>>()
+                .join("\n")
+        };
+        let check_profiles = context
+            .database
+            .get_check_profiles(interaction.guild_id)
+            .await?;
+        let check_profiles_text = if check_profiles.is_empty() {
+            "No check profiles created.".to_string()
+        } else {
+            check_profiles
+                .iter()
+                .map(|check_profile| format!("- {}", check_profile.description()))
                 .collect::<Vec<String>>()
                 .join("\n")
         };
@@ -79,10 +205,21 @@ impl ConfigShowCommand {
 
         let embed = EmbedBuilder::new()
             .color(database_guild.embed_color as u32)
+            .field(EmbedFieldBuilder::new("Alerts channel", alerts_text).build())
             .field(EmbedFieldBuilder::new("Categories", category_channel_ids_text).build())
+            .field(EmbedFieldBuilder::new("Check profiles", check_profiles_text).build())
             .field(EmbedFieldBuilder::new("Embed color", color_text).build())
+            .field(EmbedFieldBuilder::new("Expiry warnings", expiry_warnings_text).build())
             .field(EmbedFieldBuilder::new("Ignored", ignored_channel_ids_text).build())
+            .field(
+                EmbedFieldBuilder::new("Invalid invite alerts", invalid_invite_alerts_text).build(),
+            )
             .field(EmbedFieldBuilder::new("Results channel", result_text).build())
+            .field(EmbedFieldBuilder::new("Counting", invite_counting_mode_text).build())
+            .field(EmbedFieldBuilder::new("Rules", rule_lines.join("\n")).build())
+            .field(EmbedFieldBuilder::new("Scanning", scan_text).build())
+            .field(EmbedFieldBuilder::new("Schedule", schedule_text).build())
+            .field(EmbedFieldBuilder::new("Tracked channels", tracked_channel_ids_text).build())
             .build();
 
         interaction
@@ -17,11 +17,14 @@ use crate::{
         },
         Result,
     },
-    utility::error::Error,
+    utility::{constants::TRACKED_CHANNELS_CATEGORY_NAME, error::Error},
 };
 
 #[derive(CommandModel, CreateCommand)]
-#[command(desc = "Count channels within (added) categories", name = "counts")]
+#[command(
+    desc = "Count channels within (added) categories and tracked channels",
+    name = "counts"
+)]
 pub struct CountsCommand {}
 
 impl CountsCommand {
@@ -40,29 +43,40 @@ impl CountsCommand {
             return Err(Error::Custom("Please kick and invite Sakura.".to_owned()))
         };
 
-        if database_guild.category_channel_ids.is_empty() {
+        if database_guild.category_channel_ids.is_empty()
+            && database_guild.tracked_channel_ids.is_empty()
+        {
             return Err(Error::Custom(
-                "There are no categories for Sakura to check.".to_owned(),
+                "There are no categories or tracked channels for Sakura to check.".to_owned(),
             ));
         }
 
-        let mut unsorted_category_counts: HashMap<Id<ChannelMarker>, (u8, u8, u8)> = HashMap::new();
+        let mut unsorted_category_counts: HashMap<Id<ChannelMarker>, (u32, u32, u32, u32)> =
+            HashMap::new();
 
         if let Some(cached_guild) = context.cache.get_guild(interaction.guild_id) {
             for channel_id in cached_guild.channel_ids.read().clone().into_iter() {
                 let Some(channel) = context.cache.get_channel(channel_id) else {
                     continue;
                 };
-                let Some(parent_id) = channel.parent_id else {
+
+                if channel.is_forum() {
+                    continue;
+                }
+
+                let Some(parent_id) = context.cache.get_checked_category_id(&channel) else {
                     continue;
                 };
 
-                if !database_guild.category_channel_ids.contains(&parent_id) {
+                // Channels that only a check profile tracks share the guild's id as well
+                if parent_id == interaction.guild_id.cast()
+                    && !channel.is_tracked_by(&database_guild.tracked_channel_ids)
+                {
                     continue;
                 }
 
                 if !unsorted_category_counts.contains_key(&parent_id) {
-                    unsorted_category_counts.insert(parent_id, (0, 0, 0));
+                    unsorted_category_counts.insert(parent_id, (0, 0, 0, 0));
                 }
 
                 let Some(category_counts) = unsorted_category_counts.get_mut(&parent_id) else {
@@ -77,9 +91,16 @@ impl CountsCommand {
                     category_counts.1 += 1;
                 }
 
-                if database_guild.ignored_channel_ids.contains(&channel_id) {
+                if channel.is_thread() {
                     category_counts.2 += 1;
                 }
+
+                if context
+                    .cache
+                    .is_ignored_channel(&database_guild, channel_id)
+                {
+                    category_counts.3 += 1;
+                }
             }
         }
 
@@ -105,14 +126,23 @@ impl CountsCommand {
             }
         });
 
+        if !database_guild.tracked_channel_ids.is_empty() {
+            sorted_category_channels.push((
+                interaction.guild_id.cast(),
+                TRACKED_CHANNELS_CATEGORY_NAME.to_owned(),
+                0,
+            ));
+        }
+
         for sorted_category_channel in sorted_category_channels {
-            let (announcement, text, ignored) = unsorted_category_counts
+            let (announcement, text, threads, ignored) = unsorted_category_counts
                 .get(&sorted_category_channel.0)
                 .cloned()
-                .unwrap_or((0, 0, 0));
+                .unwrap_or((0, 0, 0, 0));
             let value = vec![
                 format!("- {announcement} announcement channel(s)"),
                 format!("- {text} text channel(s)"),
+                format!("- {threads} forum post(s) and thread(s)"),
                 format!("- {ignored} ignored channel(s)"),
             ]
             .join("\n");
@@ -0,0 +1,169 @@
+use thousands::Separable;
+use twilight_interactions::command::{CommandModel, CreateCommand};
+use twilight_util::builder::embed::{EmbedBuilder, EmbedFieldBuilder, EmbedFooterBuilder};
+
+use crate::{
+    types::{
+        context::Context,
+        interaction::{
+            ApplicationCommandInteraction,
+            DeferInteractionPayload,
+            UpdateResponsePayload,
+        },
+        Result,
+    },
+    utility::{error::Error, time::humanize},
+};
+
+const CHECKS_PER_PAGE: i64 = 5;
+
+#[derive(CommandModel, CreateCommand)]
+#[command(desc = "Show past invite checks", name = "history")]
+pub struct HistoryCommand {
+    #[command(desc = "The page to show", min_value = 1)]
+    page: Option<i64>,
+}
+
+impl HistoryCommand {
+    pub async fn run(
+        context: &Context,
+        interaction: &mut ApplicationCommandInteraction<'_>,
+    ) -> Result<()> {
+        interaction
+            .context
+            .defer(DeferInteractionPayload {
+                ephemeral: false,
+            })
+            .await?;
+
+        let options = HistoryCommand::from_interaction(interaction.input_data())?;
+        let Some(database_guild) = context.database.get_guild(interaction.guild_id).await else {
+            return Err(Error::Custom("Please kick and invite Sakura.".to_owned()));
+        };
+        let invite_check_count = context
+            .database
+            .get_invite_check_count(interaction.guild_id)
+            .await?;
+
+        if invite_check_count == 0 {
+            return Err(Error::Custom(
+                "Sakura has not run any invite checks in this server yet.".to_owned(),
+            ));
+        }
+
+        let page_count = (invite_check_count + CHECKS_PER_PAGE - 1) / CHECKS_PER_PAGE;
+        let page = options.page.unwrap_or(1);
+
+        if page > page_count {
+            return Err(Error::Custom(format!(
+                "There are only **{page_count}** page(s) of invite checks."
+            )));
+        }
+
+        // The extra check gives the oldest check on this page something to compare against
+        let invite_checks = context
+            .database
+            .get_invite_checks(
+                interaction.guild_id,
+                CHECKS_PER_PAGE + 1,
+                (page - 1) * CHECKS_PER_PAGE,
+            )
+            .await?;
+        let mut embed_builder = EmbedBuilder::new()
+            .color(database_guild.embed_color as u32)
+            .footer(EmbedFooterBuilder::new(format!(
+                "Page {page} of {page_count}"
+            )))
+            .title("Invite check history");
+
+        for (index, invite_check) in invite_checks
+            .iter()
+            .take(CHECKS_PER_PAGE as usize)
+            .enumerate()
+        {
+            // Trends only compare checks of the same profile
+            let previous_invite_check = invite_checks
+                .iter()
+                .skip(index + 1)
+                .find(|previous| previous.profile.eq(&invite_check.profile));
+            let check_number = invite_check_count - (page - 1) * CHECKS_PER_PAGE - index as i64;
+            let elapsed_time = humanize(
+                ((invite_check.end_time.unix_timestamp_nanos()
+                    - invite_check.start_time.unix_timestamp_nanos())
+                    / 1_000_000) as u128,
+            );
+            let value = [
+                format!(
+                    "- Started <t:{}:f> and finished <t:{}:t> ({elapsed_time})",
+                    invite_check.start_time.unix_timestamp(),
+                    invite_check.end_time.unix_timestamp()
+                ),
+                format!(
+                    "- **{}** channel(s) checked{}",
+                    invite_check.channels.separate_with_commas(),
+                    trend(
+                        invite_check.channels,
+                        previous_invite_check.map(|previous| previous.channels)
+                    )
+                ),
+                format!(
+                    "- **{}** valid invite(s){}",
+                    invite_check.valid_invites.separate_with_commas(),
+                    trend(
+                        invite_check.valid_invites,
+                        previous_invite_check.map(|previous| previous.valid_invites)
+                    )
+                ),
+                format!(
+                    "- **{}** invalid invite(s){}",
+                    invite_check.invalid_invites.separate_with_commas(),
+                    trend(
+                        invite_check.invalid_invites,
+                        previous_invite_check.map(|previous| previous.invalid_invites)
+                    )
+                ),
+                format!(
+                    "- **{}** unknown invite(s){}",
+                    invite_check.unknown_invites.separate_with_commas(),
+                    trend(
+                        invite_check.unknown_invites,
+                        previous_invite_check.map(|previous| previous.unknown_invites)
+                    )
+                ),
+            ]
+            .join("\n");
+
+            embed_builder = embed_builder.field(EmbedFieldBuilder::new(
+                match &invite_check.profile {
+                    Some(profile) => format!("Check #{check_number} (`{profile}`)"),
+                    None => format!("Check #{check_number}"),
+                },
+                value,
+            ));
+        }
+
+        interaction
+            .context
+            .update_response(UpdateResponsePayload {
+                embeds: vec![embed_builder.build()],
+                ..Default::default()
+            })
+            .await?;
+
+        Ok(())
+    }
+}
+
+fn trend(
+    current: i64,
+    previous: Option<i64>,
+) -> String {
+    match previous.map(|previous| current - previous) {
+        None => "".to_owned(),
+        Some(0) => " (no change)".to_owned(),
+        Some(difference) if difference > 0 => {
+            format!(" (📈 +{})", difference.separate_with_commas())
+        }
+        Some(difference) => format!(" (📉 {})", difference.separate_with_commas()),
+    }
+}
@@ -103,8 +103,13 @@ impl InfoCommand {
                             vec![
                                 "- Set a channel to send invite check results in using the `/config set-results-channel` command.",
                                 "- Add categories to check using the `/config add-category-channel` command.",
-                                "- Add channels to ignore using the `/config add-ignored-channel` command.",
-                                "- Run an invite check using the `/check` command."
+                                "- Add single channels to check using the `/config add-tracked-channel` command.",
+                                "- Add channels to ignore using the `/config add-ignored-channel` and `/config add-ignore-rule` commands.",
+                                "- Run an invite check using the `/check run` command.",
+                                "- Run invite checks automatically using the `/config set-schedule` command.",
+                                "- Flag partner servers that break your rules using the `/config rules set-minimum-member-count`, `/config rules set-allow-duplicate-guilds`, and `/config rules add-blocked-guild` commands.",
+                                "- Set what single channels are expected to have using the `/config channel` commands.",
+                                "- Check separate partner programs on their own using the `/config profile` commands and the `profile` option of `/check run`."
                             ].join("\n")
                         ).build()
                     )
@@ -2,6 +2,7 @@ pub mod check;
 pub mod check_message;
 pub mod config;
 pub mod counts;
+pub mod history;
 pub mod info;
 pub mod latency;
 pub mod stats;
@@ -15,6 +16,7 @@ pub fn get_commands() -> Vec<Command> {
         check_message::CheckMessageCommand::create_command(),
         config::ConfigCommand::create_command().into(),
         counts::CountsCommand::create_command().into(),
+        history::HistoryCommand::create_command().into(),
         info::InfoCommand::create_command().into(),
         latency::LatencyCommand::create_command().into(),
         stats::StatsCommand::create_command().into(),
@@ -11,6 +11,11 @@ pub async fn handle_channel_delete(
     let channel_id = payload.id;
 
     if let Some(guild_id) = payload.guild_id {
+        // Deleting a forum does not send a thread delete for each of its posts
+        for thread_id in context.cache.get_thread_ids(guild_id, channel_id) {
+            context.remove_thread(guild_id, thread_id).await?;
+        }
+
         context.cache.remove_channel(payload.id);
 
         if let Ok(updated_category_channel_ids) =
@@ -26,6 +31,14 @@ pub async fn handle_channel_delete(
         }
 
         context.database.remove_channel_messages(channel_id).await?;
+        context
+            .database
+            .remove_channel_rules(guild_id, channel_id)
+            .await?;
+        context
+            .database
+            .remove_check_profile_channel(guild_id, channel_id)
+            .await?;
     }
 
     Ok(())
@@ -0,0 +1,38 @@
+use std::sync::Arc;
+
+use twilight_model::gateway::payload::incoming::ChannelPinsUpdate;
+
+use crate::types::{context::Context, Result};
+
+pub async fn handle_channel_pins_update(
+    context: Arc<Context>,
+    payload: ChannelPinsUpdate,
+) -> Result<()> {
+    let Some(guild_id) = payload.guild_id else {
+        return Ok(());
+    };
+    let Some(channel) = context.cache.get_channel(payload.channel_id) else {
+        return Ok(());
+    };
+    if context.cache.get_checked_category_id(&channel).is_none() {
+        return Ok(());
+    }
+
+    // The event does not say which message was (un)pinned, so refetch all pins
+    let pinned_message_ids = context
+        .http
+        .pins(payload.channel_id)
+        .await?
+        .model()
+        .await?
+        .into_iter()
+        .map(|message| message.id)
+        .collect();
+
+    context
+        .database
+        .insert_pinned_messages(guild_id, payload.channel_id, pinned_message_ids)
+        .await?;
+
+    Ok(())
+}
@@ -16,6 +16,7 @@ pub fn handle_channel_update(
             parent_id: Some(payload.parent_id),
             permission_overwrites: Some(payload.permission_overwrites.clone()),
             position: Some(payload.position.unwrap_or_default()),
+            topic: Some(payload.topic.clone()),
         },
     );
     Ok(())
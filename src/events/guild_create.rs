@@ -6,16 +6,19 @@ use twilight_model::{
     id::{marker::UserMarker, Id},
 };
 
-use crate::types::{context::Context, database::GuildCreatePayload, Result};
+use crate::types::{cache::GuildUpdate, context::Context, database::GuildCreatePayload, Result};
 
 pub async fn handle_guild_create(
     context: Arc<Context>,
     payload: GuildCreate,
 ) -> Result<()> {
     let guild_id = payload.id;
-    let invite_check_category_ids =
+    let (invite_check_category_ids, tracked_channel_ids) =
         if let Some(database_guild) = context.database.get_guild(guild_id).await {
-            database_guild.category_channel_ids
+            (
+                database_guild.category_channel_ids,
+                database_guild.tracked_channel_ids,
+            )
         } else {
             context
                 .database
@@ -23,8 +26,16 @@ pub async fn handle_guild_create(
                     guild_id: guild_id.get() as i64,
                 })
                 .await?;
-            HashSet::new()
+            (HashSet::new(), HashSet::new())
         };
+    let check_profile_category_ids = context
+        .database
+        .get_check_profile_category_channel_ids(guild_id)
+        .await?;
+    let check_profile_tracked_channel_ids = context
+        .database
+        .get_check_profile_tracked_channel_ids(guild_id)
+        .await?;
     let current_user_id: Id<UserMarker> = context.application_id.cast();
     let (communication_disabled_until, role_ids) = payload
         .0
@@ -42,13 +53,23 @@ pub async fn handle_guild_create(
             )
         });
 
+    // Only active threads are sent here, archived forum posts are fetched before
+    // they are checked
     context.cache.insert_guild(
-        payload.0.channels,
+        [payload.0.channels, payload.0.threads].concat(),
         guild_id,
-        false,
         invite_check_category_ids,
         payload.0.name,
         payload.0.roles,
+        tracked_channel_ids,
+    );
+    context.cache.update_guild(
+        guild_id,
+        GuildUpdate {
+            check_profile_category_ids: Some(check_profile_category_ids),
+            check_profile_tracked_channel_ids: Some(check_profile_tracked_channel_ids),
+            ..Default::default()
+        },
     );
     context.cache.insert_current_user(
         guild_id,
@@ -2,7 +2,10 @@ use std::sync::Arc;
 
 use twilight_model::gateway::payload::incoming::GuildDelete;
 
-use crate::types::{context::Context, database::GuildDeletePayload, Result};
+use crate::{
+    tasks::unschedule_invite_checks,
+    types::{context::Context, database::GuildDeletePayload, Result},
+};
 
 pub async fn handle_guild_delete(
     context: Arc<Context>,
@@ -11,6 +14,7 @@ pub async fn handle_guild_delete(
     let guild_id = payload.id;
 
     context.cache.remove_guild(guild_id, payload.unavailable);
+    unschedule_invite_checks(&context, guild_id).await?;
     context.database.remove_guild(guild_id).await?;
     context.database.remove_guild_messages(guild_id).await?;
     context
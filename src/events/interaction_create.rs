@@ -4,6 +4,7 @@ use twilight_model::{
     application::interaction::{Interaction, InteractionData},
     channel::ChannelType,
     gateway::payload::incoming::InteractionCreate,
+    guild::Permissions,
 };
 use twilight_util::builder::embed::EmbedBuilder;
 
@@ -13,6 +14,7 @@ use crate::{
         check_message::CheckMessageCommand,
         config::ConfigCommand,
         counts::CountsCommand,
+        history::HistoryCommand,
         info::InfoCommand,
         latency::LatencyCommand,
         stats::StatsCommand,
@@ -39,6 +41,7 @@ pub async fn handle_interaction_create(
         data,
         guild_id,
         id,
+        member,
         token,
         ..
     } = payload.0;
@@ -50,9 +53,9 @@ pub async fn handle_interaction_create(
     };
     let embed_builder = EmbedBuilder::new().color(0xF8F8FF);
     let channel_id = match channel {
-        Some(channel) if vec![ChannelType::GuildAnnouncement, ChannelType::GuildText].contains(&channel.kind) => channel.id,
+        Some(channel) if vec![ChannelType::AnnouncementThread, ChannelType::GuildAnnouncement, ChannelType::GuildText, ChannelType::PublicThread].contains(&channel.kind) => channel.id,
         _ => return interaction_context.respond(ResponsePayload {
-            embeds: vec![embed_builder.description("Sakura's commands may only be run in either announcement or text channels.".to_owned()).build()],
+            embeds: vec![embed_builder.description("Sakura's commands may only be run in announcement channels, text channels or threads.".to_owned()).build()],
             ephemeral: true,
             ..Default::default()
         })
@@ -102,14 +105,18 @@ pub async fn handle_interaction_create(
         context: interaction_context,
         data,
         guild_id,
+        member_permissions: member
+            .and_then(|member| member.permissions)
+            .unwrap_or_else(Permissions::empty),
         shard_id,
     };
     let command_name = take(&mut interaction.data.name);
     let command_result = match command_name.as_str() {
         "Check message" => CheckMessageCommand::run(&context, &mut interaction).await,
-        "check" => CheckCommand::run(&context, &interaction).await,
+        "check" => CheckCommand::run(&context, &mut interaction).await,
         "config" => ConfigCommand::run(&context, &mut interaction).await,
         "counts" => CountsCommand::run(&context, &interaction).await,
+        "history" => HistoryCommand::run(&context, &mut interaction).await,
         "info" => InfoCommand::run(&context, &mut interaction).await,
         "latency" => LatencyCommand::run(&context, &interaction).await,
         "stats" => StatsCommand::run(&context, &interaction).await,
@@ -2,55 +2,79 @@ use std::sync::Arc;
 
 use twilight_model::gateway::payload::incoming::MessageUpdate;
 
-use crate::{
-    types::{context::Context, Result},
-    utility::message::get_invite_codes,
-};
+use crate::types::{context::Context, Result};
 
 pub async fn handle_message_update(
     context: Arc<Context>,
     payload: MessageUpdate,
 ) -> Result<()> {
     let Some(guild_id) = payload.guild_id else {
-        return Ok(())
-    };
-    let Some(cached_guild) = context.cache.get_guild(guild_id) else {
-        return Ok(())
+        return Ok(());
     };
     let Some(channel) = context.cache.get_channel(payload.channel_id) else {
-        return Ok(())
+        return Ok(());
+    };
+    let Some(parent_id) = context.cache.get_checked_category_id(&channel) else {
+        return Ok(());
     };
-    let Some(parent_id) = channel.parent_id else {
-        return Ok(())
+
+    // Discord also sends updates when link embeds unfurl, which leave the content
+    // and components as they were stored when the message was created
+    if payload.edited_timestamp.is_none() {
+        return Ok(());
+    }
+
+    let Some(database_guild) = context.database.get_guild(guild_id).await else {
+        return Ok(());
     };
 
-    if cached_guild
-        .invite_check_category_ids
-        .read()
-        .contains(&parent_id)
+    if payload
+        .author
+        .as_ref()
+        .is_some_and(|author| !database_guild.is_scanned_author(author.id))
     {
-        let content = payload.content.unwrap_or_default();
-        let embeds = payload.embeds.unwrap_or_default();
-        let invite_codes = get_invite_codes(content, embeds);
+        return Ok(());
+    }
+
+    // Message updates do not include components, so fetch the whole message
+    let message = context
+        .http
+        .message(payload.channel_id, payload.id)
+        .await?
+        .model()
+        .await?;
 
-        for invite_code in invite_codes.iter() {
-            context
-                .database
-                .insert_unchecked_invite(invite_code)
-                .await?;
-        }
+    if !database_guild.is_scanned_author(message.author.id) {
+        return Ok(());
+    }
 
+    let invite_codes = context
+        .get_message_invite_codes(
+            &message.content,
+            &message.embeds,
+            &message.components,
+            &message.attachments,
+        )
+        .await;
+
+    for invite_code in invite_codes.keys() {
         context
             .database
-            .insert_message(
-                guild_id,
-                payload.channel_id,
-                payload.id,
-                parent_id,
-                invite_codes,
-            )
+            .insert_unchecked_invite(invite_code)
             .await?;
     }
 
+    context
+        .database
+        .insert_message(
+            guild_id,
+            payload.channel_id,
+            payload.id,
+            parent_id,
+            invite_codes,
+            Some(message.pinned),
+        )
+        .await?;
+
     Ok(())
 }
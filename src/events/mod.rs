@@ -1,5 +1,6 @@
 mod channel_create;
 mod channel_delete;
+mod channel_pins_update;
 mod channel_update;
 mod guild_create;
 mod guild_delete;
@@ -14,6 +15,10 @@ mod ready;
 mod role_create;
 mod role_delete;
 mod role_update;
+mod thread_create;
+mod thread_delete;
+mod thread_list_sync;
+mod thread_update;
 mod unavailable_guild;
 
 use std::sync::Arc;
@@ -23,6 +28,7 @@ use twilight_gateway::Event;
 use self::{
     channel_create::handle_channel_create,
     channel_delete::handle_channel_delete,
+    channel_pins_update::handle_channel_pins_update,
     channel_update::handle_channel_update,
     guild_create::handle_guild_create,
     guild_delete::handle_guild_delete,
@@ -37,6 +43,10 @@ use self::{
     role_create::handle_role_create,
     role_delete::handle_role_delete,
     role_update::handle_role_update,
+    thread_create::handle_thread_create,
+    thread_delete::handle_thread_delete,
+    thread_list_sync::handle_thread_list_sync,
+    thread_update::handle_thread_update,
     unavailable_guild::handle_unavailable_guild,
 };
 use crate::types::{context::Context, Result};
@@ -49,6 +59,7 @@ pub async fn handle_event(
     match event {
         Event::ChannelCreate(payload) => handle_channel_create(context, *payload),
         Event::ChannelDelete(payload) => handle_channel_delete(context, *payload).await,
+        Event::ChannelPinsUpdate(payload) => handle_channel_pins_update(context, payload).await,
         Event::ChannelUpdate(payload) => handle_channel_update(context, *payload),
         Event::GuildCreate(payload) => handle_guild_create(context, *payload).await,
         Event::GuildDelete(payload) => handle_guild_delete(context, payload).await,
@@ -65,6 +76,10 @@ pub async fn handle_event(
         Event::RoleCreate(payload) => handle_role_create(context, payload),
         Event::RoleDelete(payload) => handle_role_delete(context, payload),
         Event::RoleUpdate(payload) => handle_role_update(context, payload),
+        Event::ThreadCreate(payload) => handle_thread_create(context, *payload),
+        Event::ThreadDelete(payload) => handle_thread_delete(context, payload).await,
+        Event::ThreadListSync(payload) => handle_thread_list_sync(context, payload),
+        Event::ThreadUpdate(payload) => handle_thread_update(context, *payload),
         Event::UnavailableGuild(payload) => handle_unavailable_guild(context, payload),
         _ => Ok(()),
     }
@@ -0,0 +1,14 @@
+use std::sync::Arc;
+
+use twilight_model::gateway::payload::incoming::ThreadCreate;
+
+use crate::types::{context::Context, Result};
+
+pub fn handle_thread_create(
+    context: Arc<Context>,
+    payload: ThreadCreate,
+) -> Result<()> {
+    context.cache.insert_channel(payload.0);
+
+    Ok(())
+}
@@ -0,0 +1,12 @@
+use std::sync::Arc;
+
+use twilight_model::gateway::payload::incoming::ThreadDelete;
+
+use crate::types::{context::Context, Result};
+
+pub async fn handle_thread_delete(
+    context: Arc<Context>,
+    payload: ThreadDelete,
+) -> Result<()> {
+    context.remove_thread(payload.guild_id, payload.id).await
+}
@@ -0,0 +1,16 @@
+use std::sync::Arc;
+
+use twilight_model::gateway::payload::incoming::ThreadListSync;
+
+use crate::types::{context::Context, Result};
+
+pub fn handle_thread_list_sync(
+    context: Arc<Context>,
+    payload: ThreadListSync,
+) -> Result<()> {
+    for thread in payload.threads {
+        context.cache.insert_channel(thread);
+    }
+
+    Ok(())
+}
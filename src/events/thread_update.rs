@@ -0,0 +1,15 @@
+use std::sync::Arc;
+
+use twilight_model::gateway::payload::incoming::ThreadUpdate;
+
+use crate::types::{context::Context, Result};
+
+// Archived threads stay cached so that forum posts are still checked
+pub fn handle_thread_update(
+    context: Arc<Context>,
+    payload: ThreadUpdate,
+) -> Result<()> {
+    context.cache.insert_channel(payload.0);
+
+    Ok(())
+}
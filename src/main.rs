@@ -11,12 +11,13 @@ use dotenv::dotenv;
 use futures::StreamExt;
 use twilight_gateway::{error::ReceiveMessageErrorType, stream::ShardEventStream};
 use twilight_http::Client;
+use tokio_cron_scheduler::JobScheduler;
 use twilight_model::gateway::CloseCode;
 
 use crate::{
     types::{cache::Cache, context::Context, database::Database},
     utility::{
-        constants::BOT_TOKEN,
+        constants::{check_environment_variables, BOT_TOKEN},
         gateway::{connect, reconnect},
     },
 };
@@ -24,15 +25,23 @@ use crate::{
 #[tokio::main]
 async fn main() -> types::Result<()> {
     dotenv().ok();
+    check_environment_variables()?;
 
     let http = Client::new(BOT_TOKEN.to_owned());
     let application_id = http.current_user_application().await?.model().await?.id;
     let cache = Cache::new();
     let database = Database::new()?;
+    let scheduler = JobScheduler::new().await?;
     let mut shards = connect(&http, HashMap::default()).await?;
-    let context = Arc::new(Context::new(application_id, cache, database, http));
-
-    context.database.create_tables().await?;
+    let context = Arc::new(Context::new(
+        application_id,
+        cache,
+        database,
+        http,
+        scheduler,
+    ));
+
+    context.database.run_migrations().await?;
 
     let commands = commands::get_commands();
 
@@ -0,0 +1,129 @@
+use std::time::{Duration, Instant};
+
+use twilight_model::id::{marker::GuildMarker, Id};
+
+use crate::{
+    types::{
+        alert::{InvalidInviteAlert, PendingAlerts},
+        context::Context,
+        database::InviteStatus,
+        Result,
+    },
+    utility::message::get_list_embed,
+};
+
+// Alerts are sent once a guild has had no new ones for a while, but never held
+// back for longer than the maximum delay
+const INVALID_INVITE_ALERT_DEBOUNCE: Duration = Duration::from_secs(30);
+const INVALID_INVITE_ALERT_MAX_DELAY: Duration = Duration::from_secs(300);
+
+impl Context {
+    pub async fn queue_invalid_invite_alerts(
+        &self,
+        code: &str,
+        status: InviteStatus,
+    ) -> Result<()> {
+        let messages = self
+            .database
+            .get_invalid_invite_alert_messages(code)
+            .await?;
+        let now = Instant::now();
+        let mut pending_alerts = self.pending_alerts.write();
+
+        for (guild_id, channel_id, message_id) in messages {
+            let guild_pending_alerts = pending_alerts.entry(guild_id).or_insert_with(|| {
+                PendingAlerts {
+                    alerts: Vec::new(),
+                    first_queued_at: now,
+                    last_queued_at: now,
+                }
+            });
+
+            guild_pending_alerts.alerts.push(InvalidInviteAlert {
+                channel_id,
+                code: code.to_owned(),
+                message_id,
+                status,
+            });
+            guild_pending_alerts.last_queued_at = now;
+        }
+
+        Ok(())
+    }
+
+    pub async fn send_invalid_invite_alerts(
+        &self,
+        guild_id: Id<GuildMarker>,
+        alerts: Vec<InvalidInviteAlert>,
+    ) -> Result<()> {
+        let Some(database_guild) = self.database.get_guild(guild_id).await else {
+            return Ok(());
+        };
+        let Some(alerts_channel_id) = database_guild.get_alerts_channel_id() else {
+            return Ok(());
+        };
+
+        if !database_guild.invalid_invite_alerts
+            || !self
+                .cache
+                .has_minimum_channel_permissions(alerts_channel_id)
+        {
+            return Ok(());
+        }
+
+        let lines = alerts
+            .into_iter()
+            .filter(|alert| !self.cache.is_ignored_channel(&database_guild, alert.channel_id))
+            .map(|alert| {
+                format!(
+                    "- <#{}> - `{}` is **{}** ([message](https://discord.com/channels/{guild_id}/{}/{}))",
+                    alert.channel_id,
+                    alert.code,
+                    alert.status.label(),
+                    alert.channel_id,
+                    alert.message_id
+                )
+            })
+            .collect::<Vec<String>>();
+
+        if lines.is_empty() {
+            return Ok(());
+        }
+
+        let embed = get_list_embed("Invalid invites", lines, database_guild.embed_color as u32);
+
+        self.http
+            .create_message(alerts_channel_id)
+            .embeds(&[embed])?
+            .await?;
+
+        Ok(())
+    }
+
+    pub fn take_due_invalid_invite_alerts(
+        &self
+    ) -> Vec<(Id<GuildMarker>, Vec<InvalidInviteAlert>)> {
+        let mut pending_alerts = self.pending_alerts.write();
+        let due_guild_ids = pending_alerts
+            .iter()
+            .filter(|(_, guild_pending_alerts)| guild_pending_alerts.is_due())
+            .map(|(guild_id, _)| *guild_id)
+            .collect::<Vec<Id<GuildMarker>>>();
+
+        due_guild_ids
+            .into_iter()
+            .filter_map(|guild_id| {
+                pending_alerts
+                    .remove(&guild_id)
+                    .map(|guild_pending_alerts| (guild_id, guild_pending_alerts.alerts))
+            })
+            .collect()
+    }
+}
+
+impl PendingAlerts {
+    pub fn is_due(&self) -> bool {
+        self.last_queued_at.elapsed() >= INVALID_INVITE_ALERT_DEBOUNCE
+            || self.first_queued_at.elapsed() >= INVALID_INVITE_ALERT_MAX_DELAY
+    }
+}
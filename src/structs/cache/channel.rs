@@ -1,14 +1,76 @@
 use core::matches;
-use std::sync::Arc;
+use std::{collections::HashSet, sync::Arc};
 
 use twilight_model::{
     channel::{Channel as TwilightChannel, ChannelType},
-    id::{marker::ChannelMarker, Id},
+    id::{
+        marker::{ChannelMarker, GuildMarker},
+        Id,
+    },
 };
 
-use crate::types::cache::{Cache, Channel, ChannelUpdate};
+use crate::{
+    types::{
+        cache::{Cache, Channel, ChannelUpdate},
+        database,
+    },
+    utility::constants::GUILD_MEDIA_CHANNEL_TYPE,
+};
 
 impl Cache {
+    // Threads and forum posts belong to the category of their parent channel
+    pub fn get_category_id(
+        &self,
+        channel: &Channel,
+    ) -> Option<Id<ChannelMarker>> {
+        if channel.is_thread() {
+            self.get_channel(channel.parent_id?)?.parent_id
+        } else {
+            channel.parent_id
+        }
+    }
+
+    // Tracked channels outside of added categories are grouped under the guild's
+    // id, which no real category can have. Categories and tracked channels that
+    // only a check profile uses come last
+    pub fn get_checked_category_id(
+        &self,
+        channel: &Channel,
+    ) -> Option<Id<ChannelMarker>> {
+        let guild = self.get_guild(channel.guild_id)?;
+        let category_id = self.get_category_id(channel);
+
+        if let Some(category_id) = category_id {
+            if guild
+                .invite_check_category_ids
+                .read()
+                .contains(&category_id)
+            {
+                return Some(category_id);
+            }
+        }
+
+        if channel.is_tracked_by(&guild.tracked_channel_ids.read()) {
+            return Some(guild.guild_id.cast());
+        }
+
+        if let Some(category_id) = category_id {
+            if guild
+                .check_profile_category_ids
+                .read()
+                .contains(&category_id)
+            {
+                return Some(category_id);
+            }
+        }
+
+        if channel.is_tracked_by(&guild.check_profile_tracked_channel_ids.read()) {
+            return Some(guild.guild_id.cast());
+        }
+
+        None
+    }
+
     pub fn get_channel(
         &self,
         channel_id: Id<ChannelMarker>,
@@ -18,6 +80,49 @@ impl Cache {
             .map_or(None, |read_lock| read_lock.get(&channel_id).cloned())
     }
 
+    pub fn get_thread_ids(
+        &self,
+        guild_id: Id<GuildMarker>,
+        parent_id: Id<ChannelMarker>,
+    ) -> Vec<Id<ChannelMarker>> {
+        let Some(guild) = self.get_guild(guild_id) else {
+            return Vec::new();
+        };
+
+        let channel_ids = guild.channel_ids.read().clone();
+
+        channel_ids
+            .into_iter()
+            .filter(|channel_id| {
+                self.get_channel(*channel_id).is_some_and(|channel| {
+                    channel.is_thread() && channel.parent_id.eq(&Some(parent_id))
+                })
+            })
+            .collect()
+    }
+
+    // Channels are ignored by id, or by a rule that matches the cached channel.
+    // Ignoring a forum also ignores its posts
+    pub fn is_ignored_channel(
+        &self,
+        database_guild: &database::Guild,
+        channel_id: Id<ChannelMarker>,
+    ) -> bool {
+        let Some(channel) = self.get_channel(channel_id) else {
+            return database_guild.ignored_channel_ids.contains(&channel_id);
+        };
+
+        database_guild.ignored_channel_ids.contains(&channel_id)
+            || database_guild
+                .ignore_rules
+                .iter()
+                .any(|ignore_rule| ignore_rule.matches(&channel))
+            || (channel.is_thread()
+                && channel
+                    .parent_id
+                    .is_some_and(|parent_id| self.is_ignored_channel(database_guild, parent_id)))
+    }
+
     pub fn insert_channel(
         &self,
         channel: TwilightChannel,
@@ -25,9 +130,13 @@ impl Cache {
         if let Some(guild_id) = channel.guild_id {
             if matches!(
                 channel.kind,
-                ChannelType::GuildAnnouncement
+                ChannelType::AnnouncementThread
+                    | ChannelType::GuildAnnouncement
                     | ChannelType::GuildCategory
+                    | ChannelType::GuildForum
                     | ChannelType::GuildText
+                    | ChannelType::PublicThread
+                    | GUILD_MEDIA_CHANNEL_TYPE
             ) {
                 let channel_id = channel.id;
 
@@ -45,6 +154,7 @@ impl Cache {
                         parent_id: channel.parent_id,
                         permission_overwrites: channel.permission_overwrites,
                         position: channel.position.unwrap_or_default(),
+                        topic: channel.topic,
                     }),
                 );
             }
@@ -58,7 +168,13 @@ impl Cache {
         if let Some(channel) = self.channels.write().remove(&channel_id) {
             if let Some(guild) = self.get_guild(channel.guild_id) {
                 guild.channel_ids.write().remove(&channel_id);
+                guild.check_profile_category_ids.write().remove(&channel_id);
+                guild
+                    .check_profile_tracked_channel_ids
+                    .write()
+                    .remove(&channel_id);
                 guild.invite_check_category_ids.write().remove(&channel_id);
+                guild.tracked_channel_ids.write().remove(&channel_id);
             }
         }
     }
@@ -81,8 +197,38 @@ impl Cache {
                         .permission_overwrites
                         .unwrap_or(old_channel.permission_overwrites.clone()),
                     position: update.position.unwrap_or(old_channel.position),
+                    topic: update.topic.unwrap_or(old_channel.topic.clone()),
                 }),
             );
         }
     }
 }
+
+impl Channel {
+    // Forum and media channels only hold posts, which are checked instead
+    pub fn is_forum(&self) -> bool {
+        matches!(
+            self.kind,
+            ChannelType::GuildForum | GUILD_MEDIA_CHANNEL_TYPE
+        )
+    }
+
+    // Threads and forum posts are tracked together with their parent channel
+    pub fn is_tracked_by(
+        &self,
+        tracked_channel_ids: &HashSet<Id<ChannelMarker>>,
+    ) -> bool {
+        tracked_channel_ids.contains(&self.channel_id)
+            || (self.is_thread()
+                && self
+                    .parent_id
+                    .is_some_and(|parent_id| tracked_channel_ids.contains(&parent_id)))
+    }
+
+    pub fn is_thread(&self) -> bool {
+        matches!(
+            self.kind,
+            ChannelType::AnnouncementThread | ChannelType::PublicThread
+        )
+    }
+}
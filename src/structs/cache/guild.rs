@@ -26,10 +26,10 @@ impl Cache {
         &self,
         channels: Vec<TwilightChannel>,
         guild_id: Id<GuildMarker>,
-        in_check: bool,
         invite_check_category_ids: HashSet<Id<ChannelMarker>>,
         name: String,
         roles: Vec<TwilightRole>,
+        tracked_channel_ids: HashSet<Id<ChannelMarker>>,
     ) {
         let mut channel_ids: HashSet<Id<ChannelMarker>> = HashSet::new();
         let mut role_ids: HashSet<Id<RoleMarker>> = HashSet::new();
@@ -48,11 +48,13 @@ impl Cache {
             guild_id,
             Arc::new(Guild {
                 channel_ids: RwLock::new(channel_ids),
+                check_profile_category_ids: RwLock::new(HashSet::new()),
+                check_profile_tracked_channel_ids: RwLock::new(HashSet::new()),
                 guild_id,
-                in_check,
                 invite_check_category_ids: RwLock::new(invite_check_category_ids),
                 name,
                 role_ids: RwLock::new(role_ids),
+                tracked_channel_ids: RwLock::new(tracked_channel_ids),
             }),
         );
         self.remove_unavailable_guild(guild_id)
@@ -89,8 +91,17 @@ impl Cache {
                 guild_id,
                 Arc::new(Guild {
                     channel_ids: RwLock::new(old_guild.channel_ids.read().clone()),
+                    check_profile_category_ids: RwLock::new(
+                        update
+                            .check_profile_category_ids
+                            .unwrap_or(old_guild.check_profile_category_ids.read().clone()),
+                    ),
+                    check_profile_tracked_channel_ids: RwLock::new(
+                        update
+                            .check_profile_tracked_channel_ids
+                            .unwrap_or(old_guild.check_profile_tracked_channel_ids.read().clone()),
+                    ),
                     guild_id,
-                    in_check: update.in_check.unwrap_or(old_guild.in_check),
                     invite_check_category_ids: RwLock::new(
                         update
                             .invite_check_category_ids
@@ -98,6 +109,11 @@ impl Cache {
                     ),
                     name: update.name.unwrap_or(old_guild.name.clone()),
                     role_ids: RwLock::new(old_guild.role_ids.read().clone()),
+                    tracked_channel_ids: RwLock::new(
+                        update
+                            .tracked_channel_ids
+                            .unwrap_or(old_guild.tracked_channel_ids.read().clone()),
+                    ),
                 }),
             );
         }
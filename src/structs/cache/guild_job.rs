@@ -0,0 +1,229 @@
+use std::sync::{
+    atomic::{AtomicBool, Ordering},
+    Arc,
+};
+
+use time::OffsetDateTime;
+use twilight_model::id::{marker::GuildMarker, Id};
+
+use crate::{
+    types::{
+        cache::{Cache, GuildJob, GuildJobGuard, GuildJobKind},
+        Result,
+    },
+    utility::{constants::GUILD_JOB_TIMEOUT, error::Error},
+};
+
+impl Cache {
+    pub fn cancel_guild_job(
+        &self,
+        guild_id: Id<GuildMarker>,
+        profile: Option<&str>,
+    ) -> Option<Arc<GuildJob>> {
+        let mut guild_jobs = self.guild_jobs.write();
+        let jobs = guild_jobs.get_mut(&guild_id)?;
+        let index = jobs
+            .iter()
+            .position(|job| job.profile.as_deref() == profile)?;
+        let job = jobs.remove(index);
+
+        if jobs.is_empty() {
+            guild_jobs.remove(&guild_id);
+        }
+
+        job.cancelled.store(true, Ordering::Relaxed);
+
+        Some(job)
+    }
+
+    // Invite checks of different profiles can run side by side, but anything else
+    // needs the guild to itself
+    pub fn lock_guild(
+        &self,
+        guild_id: Id<GuildMarker>,
+        kind: GuildJobKind,
+    ) -> Result<GuildJobGuard<'_>> {
+        self.lock_guild_job(guild_id, kind, None)
+    }
+
+    pub fn lock_guild_invite_check(
+        &self,
+        guild_id: Id<GuildMarker>,
+        profile: Option<String>,
+    ) -> Result<GuildJobGuard<'_>> {
+        self.lock_guild_job(guild_id, GuildJobKind::InviteCheck, profile)
+    }
+
+    fn lock_guild_job(
+        &self,
+        guild_id: Id<GuildMarker>,
+        kind: GuildJobKind,
+        profile: Option<String>,
+    ) -> Result<GuildJobGuard<'_>> {
+        let mut guild_jobs = self.guild_jobs.write();
+        let jobs = guild_jobs.entry(guild_id).or_default();
+        let job = Arc::new(GuildJob {
+            cancelled: AtomicBool::new(false),
+            kind,
+            profile,
+            started_at: OffsetDateTime::now_utc(),
+        });
+
+        if let Some(other_job) = jobs
+            .iter()
+            .find(|other_job| other_job.conflicts_with(&job) && !other_job.is_stale())
+        {
+            return Err(Error::Custom(format!(
+                "Sakura is {} at the moment. Please wait until this is done before trying again, or use `/check cancel`.",
+                other_job.description()
+            )));
+        }
+
+        jobs.retain(|other_job| {
+            if other_job.conflicts_with(&job) {
+                other_job.cancelled.store(true, Ordering::Relaxed);
+
+                return false;
+            }
+
+            true
+        });
+        jobs.push(job.clone());
+
+        Ok(GuildJobGuard {
+            cache: self,
+            guild_id,
+            job,
+        })
+    }
+}
+
+impl GuildJob {
+    pub fn conflicts_with(
+        &self,
+        other: &GuildJob,
+    ) -> bool {
+        !matches!(
+            (self.kind, other.kind),
+            (GuildJobKind::InviteCheck, GuildJobKind::InviteCheck)
+        ) || self.profile == other.profile
+    }
+
+    pub fn description(&self) -> String {
+        match &self.profile {
+            Some(profile) => format!("{} for `{profile}`", self.kind.description()),
+            None => self.kind.description().to_owned(),
+        }
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::Relaxed)
+    }
+
+    pub fn is_stale(&self) -> bool {
+        OffsetDateTime::now_utc() - self.started_at > GUILD_JOB_TIMEOUT
+    }
+}
+
+impl GuildJobGuard<'_> {
+    pub fn ensure_not_cancelled(&self) -> Result<()> {
+        if self.job.is_cancelled() {
+            return Err(Error::Custom(format!(
+                "Sakura stopped {} because it was cancelled.",
+                self.job.description()
+            )));
+        }
+
+        Ok(())
+    }
+}
+
+impl Drop for GuildJobGuard<'_> {
+    fn drop(&mut self) {
+        let mut guild_jobs = self.cache.guild_jobs.write();
+        let Some(jobs) = guild_jobs.get_mut(&self.guild_id) else {
+            return;
+        };
+
+        // A cancelled or stale job may have been replaced by a newer one
+        jobs.retain(|job| !Arc::ptr_eq(job, &self.job));
+
+        if jobs.is_empty() {
+            guild_jobs.remove(&self.guild_id);
+        }
+    }
+}
+
+impl GuildJobKind {
+    pub fn description(&self) -> &'static str {
+        match self {
+            Self::AddCategoryChannel => "adding a category",
+            Self::AddTrackedChannel => "adding a tracked channel",
+            Self::InviteCheck => "running an invite check",
+            Self::RecycleInvites => "rechecking old messages",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use twilight_model::id::Id;
+
+    use crate::types::cache::{Cache, GuildJobKind};
+
+    #[test]
+    fn locks_invite_checks_per_profile() {
+        let cache = Cache::new();
+        let guild_id = Id::new(1);
+        let guild_check = cache.lock_guild_invite_check(guild_id, None).unwrap();
+        let profile_check = cache
+            .lock_guild_invite_check(guild_id, Some("partners".to_owned()))
+            .unwrap();
+
+        assert!(cache.lock_guild_invite_check(guild_id, None).is_err());
+        assert!(cache
+            .lock_guild_invite_check(guild_id, Some("partners".to_owned()))
+            .is_err());
+        assert!(cache
+            .lock_guild_invite_check(Id::new(2), Some("partners".to_owned()))
+            .is_ok());
+        assert!(cache
+            .lock_guild(guild_id, GuildJobKind::AddCategoryChannel)
+            .is_err());
+
+        drop(guild_check);
+
+        assert!(cache
+            .lock_guild(guild_id, GuildJobKind::AddCategoryChannel)
+            .is_err());
+
+        drop(profile_check);
+
+        let category_job = cache
+            .lock_guild(guild_id, GuildJobKind::AddCategoryChannel)
+            .unwrap();
+
+        assert!(cache
+            .lock_guild_invite_check(guild_id, Some("partners".to_owned()))
+            .is_err());
+
+        drop(category_job);
+
+        assert!(cache.guild_jobs.read().is_empty());
+    }
+
+    #[test]
+    fn cancels_invite_checks_per_profile() {
+        let cache = Cache::new();
+        let guild_id = Id::new(1);
+        let guild_check = cache.lock_guild_invite_check(guild_id, None).unwrap();
+        let profile_check = cache
+            .lock_guild_invite_check(guild_id, Some("partners".to_owned()))
+            .unwrap();
+
+        assert!(cache.cancel_guild_job(guild_id, Some("other")).is_none());
+        assert!(cache.cancel_guild_job(guild_id, Some("partners")).is_some());
+        assert!(profile_check.ensure_not_cancelled().is_err());
+        assert!(guild_check.ensure_not_cancelled().is_ok());
+    }
+}
@@ -1,6 +1,7 @@
 mod channel;
 mod current_user;
 mod guild;
+mod guild_job;
 mod role;
 mod unavailable_guild;
 
@@ -23,7 +24,16 @@ impl Cache {
         &self,
         channel_id: Id<ChannelMarker>,
     ) -> bool {
-        let (guild_id, kind, permission_overwrites) = match self.get_channel(channel_id) {
+        // Threads have no overwrites of their own and inherit their parent's
+        let channel = match self.get_channel(channel_id) {
+            Some(channel) if channel.is_thread() => {
+                channel
+                    .parent_id
+                    .and_then(|parent_id| self.get_channel(parent_id))
+            }
+            channel => channel,
+        };
+        let (guild_id, kind, permission_overwrites) = match channel {
             Some(channel) => {
                 (
                     channel.guild_id,
@@ -80,6 +90,7 @@ impl Cache {
         Self {
             channels: RwLock::new(HashMap::new()),
             current_users: RwLock::new(HashMap::new()),
+            guild_jobs: RwLock::new(HashMap::new()),
             guilds: RwLock::new(HashMap::new()),
             roles: RwLock::new(HashMap::new()),
             unavailable_guilds: RwLock::new(HashSet::new()),
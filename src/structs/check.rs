@@ -0,0 +1,1066 @@
+use std::{
+    cmp::{max, Reverse},
+    collections::HashMap,
+    time::Duration,
+};
+
+use thousands::Separable;
+use time::OffsetDateTime;
+use tokio::time::sleep;
+use twilight_model::{
+    channel::message::Embed,
+    id::{
+        marker::{ChannelMarker, GuildMarker},
+        Id,
+    },
+};
+use twilight_util::builder::embed::{EmbedBuilder, EmbedFieldBuilder};
+
+use crate::{
+    types::{
+        cache::Channel,
+        check::{
+            CategoryReport,
+            ChannelChange,
+            CheckOptions,
+            CheckReport,
+            DuplicateGuild,
+            InviteCheck,
+            RuleViolation,
+        },
+        context::Context,
+        database::{
+            self,
+            ChannelRule,
+            InviteCheckChannel,
+            InviteCheckChannelStatus,
+            InviteCheckCreatePayload,
+            InviteCountingMode,
+            InviteStatus,
+            InviteTarget,
+        },
+        Result,
+    },
+    utility::{
+        constants::TRACKED_CHANNELS_CATEGORY_NAME,
+        error::Error,
+        message::get_list_embed,
+        time::humanize,
+    },
+};
+
+// Stays below the 4096 characters Discord allows in an embed description
+const CATEGORY_EMBED_DESCRIPTION_LENGTH: usize = 4000;
+// Keeps category embeds short when a channel has many dead invites
+const MAX_INVALID_INVITE_LOCATIONS: usize = 3;
+
+impl Context {
+    pub async fn prepare_invite_check(
+        &self,
+        guild_id: Id<GuildMarker>,
+        options: CheckOptions,
+    ) -> Result<InviteCheck<'_>> {
+        let (Some(cached_guild), Some(mut database_guild)) = (
+            self.cache.get_guild(guild_id),
+            self.database.get_guild(guild_id).await,
+        ) else {
+            return Err(Error::Custom("Please kick and invite Sakura.".to_owned()));
+        };
+
+        if let Some(profile) = &options.profile {
+            let Some(check_profile) = self.database.get_check_profile(guild_id, profile).await?
+            else {
+                return Err(Error::Custom(format!(
+                    "There is no check profile named `{profile}`."
+                )));
+            };
+
+            if check_profile.category_channel_ids.is_empty()
+                && check_profile.tracked_channel_ids.is_empty()
+            {
+                return Err(Error::Custom(format!(
+                    "There are no categories or tracked channels in the `{profile}` check profile for Sakura to check."
+                )));
+            }
+
+            database_guild = database_guild.with_check_profile(check_profile);
+        }
+
+        if database_guild.category_channel_ids.is_empty()
+            && database_guild.tracked_channel_ids.is_empty()
+        {
+            return Err(Error::Custom(
+                "There are no categories or tracked channels for Sakura to check.".to_owned(),
+            ));
+        }
+
+        let previous_channels = if options.diff {
+            let Some(previous_channels) = self
+                .database
+                .get_latest_invite_check_channels(guild_id, options.profile.as_deref())
+                .await?
+            else {
+                return Err(Error::Custom(
+                    "There is no previous invite check to compare against. Please run an invite check without `diff` first."
+                        .to_owned(),
+                ));
+            };
+
+            Some(previous_channels)
+        } else {
+            None
+        };
+        let guild_job = self
+            .cache
+            .lock_guild_invite_check(guild_id, options.profile.clone())?;
+
+        Ok(InviteCheck {
+            cached_guild,
+            context: self,
+            database_guild,
+            guild_job,
+            previous_channels,
+            profile: options.profile,
+        })
+    }
+}
+
+impl InviteCheck<'_> {
+    pub fn embed_color(&self) -> u32 {
+        self.database_guild.embed_color as u32
+    }
+
+    pub fn results_channel_id(&self) -> Result<Id<ChannelMarker>> {
+        let Some(results_channel_id) = self.database_guild.results_channel_id else {
+            return Err(Error::Custom(
+                "You have not set a \"results channel\".".to_owned(),
+            ));
+        };
+
+        if self.context.cache.get_channel(results_channel_id).is_none() {
+            return Err(Error::Custom(
+                "Please set another \"results channel\".".to_owned(),
+            ));
+        }
+        if !self
+            .context
+            .cache
+            .has_minimum_channel_permissions(results_channel_id)
+        {
+            return Err(Error::Custom(format!("Sakura is unable to either view <#{results_channel_id}> or send messages in the channel.")));
+        }
+
+        Ok(results_channel_id)
+    }
+
+    // Borrows the check, so that the guild stays locked until the report is sent
+    pub async fn run(&self) -> Result<CheckReport> {
+        let context = self.context;
+        let guild_id = self.cached_guild.guild_id;
+        let mut report = self.build_report().await?;
+
+        self.guild_job.ensure_not_cancelled()?;
+
+        let (valid, invalid, unknown, temporary) = report.total_invites();
+
+        report.check_id = context
+            .database
+            .insert_invite_check_create_event(
+                InviteCheckCreatePayload {
+                    guild_id: guild_id.get() as i64,
+                    start_time: report.start_time,
+                    end_time: report.end_time,
+                    channels: report.total_channels() as i64,
+                    valid_invites: (valid + temporary) as i64,
+                    invalid_invites: invalid as i64,
+                    unknown_invites: unknown as i64,
+                    profile: report.profile.clone(),
+                },
+                &report
+                    .channels()
+                    .cloned()
+                    .collect::<Vec<InviteCheckChannel>>(),
+            )
+            .await?;
+
+        Ok(report)
+    }
+
+    pub async fn send_report(
+        &self,
+        channel_id: Id<ChannelMarker>,
+        report: &CheckReport,
+    ) -> Result<()> {
+        let http = &self.context.http;
+
+        for embed in report.embeds() {
+            self.guild_job.ensure_not_cancelled()?;
+            http.create_message(channel_id).embeds(&[embed])?.await?;
+
+            sleep(Duration::from_secs(1)).await;
+        }
+
+        self.guild_job.ensure_not_cancelled()?;
+        http.create_message(channel_id)
+            .embeds(&[report.results_embed()])?
+            .await?;
+
+        Ok(())
+    }
+
+    // Unlike the cache, this only knows about the categories and tracked channels
+    // of the check profile, if one is used
+    fn get_checked_category_id(
+        &self,
+        channel: &Channel,
+    ) -> Option<Id<ChannelMarker>> {
+        let cache = &self.context.cache;
+
+        if let Some(category_id) = cache.get_category_id(channel) {
+            if self
+                .database_guild
+                .category_channel_ids
+                .contains(&category_id)
+            {
+                return Some(category_id);
+            }
+        }
+
+        if channel.is_tracked_by(&self.database_guild.tracked_channel_ids) {
+            Some(self.cached_guild.guild_id.cast())
+        } else {
+            None
+        }
+    }
+
+    async fn build_report(&self) -> Result<CheckReport> {
+        let context = self.context;
+        let guild_id = self.cached_guild.guild_id;
+        let start_time = OffsetDateTime::now_utc();
+        let mut sorted_category_channels = self
+            .database_guild
+            .category_channel_ids
+            .iter()
+            .filter_map(|channel_id| {
+                context
+                    .cache
+                    .get_channel(*channel_id)
+                    .map(|channel| (channel.channel_id, channel.name.clone(), channel.position))
+            })
+            .collect::<Vec<(Id<ChannelMarker>, String, i32)>>();
+
+        sorted_category_channels.sort_unstable_by(|a, b| {
+            let sort_ordering = a.2.cmp(&b.2);
+
+            if sort_ordering.is_eq() {
+                a.1.cmp(&b.1)
+            } else {
+                sort_ordering
+            }
+        });
+
+        if !self.database_guild.tracked_channel_ids.is_empty() {
+            sorted_category_channels.push((
+                guild_id.cast(),
+                TRACKED_CHANNELS_CATEGORY_NAME.to_owned(),
+                0,
+            ));
+        }
+
+        let mut child_channels_in_categories: HashMap<
+            Id<ChannelMarker>,
+            Vec<(Id<ChannelMarker>, i32)>,
+        > = HashMap::new();
+
+        let checked_forum_ids = self
+            .cached_guild
+            .channel_ids
+            .read()
+            .clone()
+            .into_iter()
+            .filter(|channel_id| {
+                context
+                    .cache
+                    .get_channel(*channel_id)
+                    .is_some_and(|channel| {
+                        channel.is_forum() && self.get_checked_category_id(&channel).is_some()
+                    })
+            })
+            .collect::<Vec<Id<ChannelMarker>>>();
+
+        for forum_id in checked_forum_ids {
+            self.guild_job.ensure_not_cancelled()?;
+            context.cache_archived_threads(forum_id).await?;
+        }
+
+        self.guild_job.ensure_not_cancelled()?;
+
+        for channel_id in self.cached_guild.channel_ids.read().clone().into_iter() {
+            let Some(channel) = context.cache.get_channel(channel_id) else {
+                continue;
+            };
+
+            if channel.is_forum() {
+                continue;
+            }
+
+            let Some(parent_id) = self.get_checked_category_id(&channel) else {
+                continue;
+            };
+
+            // Threads are listed after the channel or forum they were posted in
+            let position = if channel.is_thread() {
+                channel
+                    .parent_id
+                    .and_then(|parent_id| context.cache.get_channel(parent_id))
+                    .map_or(channel.position, |parent| parent.position)
+            } else {
+                channel.position
+            };
+
+            child_channels_in_categories
+                .entry(parent_id)
+                .or_default()
+                .push((channel_id, position));
+        }
+
+        let guild_invite_counts = context.database.get_guild_invite_counts(guild_id).await?;
+        let invite_status_counts = context
+            .database
+            .get_guild_invite_status_counts(guild_id)
+            .await?;
+        let invite_targets = context.database.get_guild_invite_targets(guild_id).await?;
+        let invalid_invite_locations = context
+            .database
+            .get_guild_invalid_invite_locations(guild_id)
+            .await?;
+        let channel_rules = context
+            .database
+            .get_channel_rules(guild_id, self.profile.as_deref())
+            .await?;
+        let mut categories = Vec::with_capacity(sorted_category_channels.len());
+
+        for (category_id, name, _) in sorted_category_channels {
+            let mut child_channels = child_channels_in_categories
+                .remove(&category_id)
+                .unwrap_or_default();
+
+            child_channels.sort_unstable_by_key(|(channel_id, position)| (*position, *channel_id));
+
+            let channels = child_channels
+                .into_iter()
+                .map(|(channel_id, position)| {
+                    let invite_counts = guild_invite_counts.get(&channel_id).cloned();
+                    let (permanent, invalid, unknown, temporary) =
+                        invite_counts.unwrap_or_default();
+                    // Temporary invites are only reported separately when permanent invites are
+                    // required and the channel does not allow them, otherwise they count as valid
+                    let (valid, temporary) = if self.database_guild.require_permanent_invites
+                        && !channel_rules
+                            .get(&channel_id)
+                            .is_some_and(|channel_rule| channel_rule.allow_temporary_invites)
+                    {
+                        (permanent, temporary)
+                    } else {
+                        (permanent + temporary, 0)
+                    };
+
+                    InviteCheckChannel {
+                        channel_id,
+                        category_id,
+                        position,
+                        valid_invites: valid as i32,
+                        invalid_invites: invalid as i32,
+                        unknown_invites: unknown as i32,
+                        temporary_invites: temporary as i32,
+                        is_ignored: context
+                            .cache
+                            .is_ignored_channel(&self.database_guild, channel_id),
+                        is_untracked: invite_counts.is_none(),
+                        breaks_rules: false,
+                    }
+                })
+                .collect();
+
+            categories.push(CategoryReport {
+                name,
+                channels,
+            });
+        }
+
+        let duplicate_guilds = get_duplicate_guilds(&categories, &invite_targets);
+        let rule_violations = get_rule_violations(
+            &self.database_guild,
+            &categories,
+            &invite_targets,
+            &duplicate_guilds,
+            &channel_rules,
+        );
+
+        for channel in categories
+            .iter_mut()
+            .flat_map(|category| category.channels.iter_mut())
+        {
+            channel.breaks_rules = rule_violations.contains_key(&channel.channel_id);
+        }
+
+        let mut report = CheckReport {
+            categories,
+            changes: None,
+            channel_rules,
+            check_id: 0,
+            duplicate_guilds,
+            embed_color: self.embed_color(),
+            end_time: OffsetDateTime::now_utc(),
+            invalid_invite_locations,
+            invite_counting_mode: self.database_guild.invite_counting_mode,
+            invite_status_counts,
+            profile: self.profile.clone(),
+            require_permanent_invites: self.database_guild.require_permanent_invites,
+            rule_violations,
+            start_time,
+        };
+
+        if let Some(previous_channels) = &self.previous_channels {
+            report.changes = Some(get_channel_changes(previous_channels, &report));
+        }
+
+        Ok(report)
+    }
+}
+
+impl CheckReport {
+    pub fn channels(&self) -> impl Iterator<Item = &InviteCheckChannel> {
+        self.categories
+            .iter()
+            .flat_map(|category| category.channels.iter())
+    }
+
+    pub fn embeds(&self) -> Vec<Embed> {
+        let mut embeds = match &self.changes {
+            Some(changes) => get_diff_embeds(changes, self.embed_color),
+            None => {
+                self.categories
+                    .iter()
+                    .flat_map(|category| self.category_embeds(category))
+                    .collect()
+            }
+        };
+
+        if let Some(embed) = self.duplicate_guilds_embed() {
+            embeds.push(embed);
+        }
+
+        embeds
+    }
+
+    // Large categories are split over several embeds to stay within Discord's
+    // description limit
+    pub fn category_embeds(
+        &self,
+        category: &CategoryReport,
+    ) -> Vec<Embed> {
+        let channel_blocks = if category.channels.is_empty() {
+            vec!["No channels to check in this category.".to_owned()]
+        } else {
+            category
+                .channels
+                .iter()
+                .map(|channel| {
+                    let channel_id = channel.channel_id;
+                    let valid = channel.valid_invites;
+                    let invalid = channel.invalid_invites;
+                    let unknown = channel.unknown_invites;
+                    let temporary = channel.temporary_invites;
+                    let total = valid + invalid + unknown + temporary;
+                    let line = match channel.status() {
+                        InviteCheckChannelStatus::Ignored => {
+                            format!("⚪ <#{channel_id}> - **IGNORED**")
+                        }
+                        InviteCheckChannelStatus::Untracked => {
+                            format!("⚪ <#{channel_id}> - **UNTRACKED CHANNEL**")
+                        }
+                        InviteCheckChannelStatus::Unknown => {
+                            format!(
+                                "⚪ <#{channel_id}> - **{total}** total (**{unknown}** unknown: {})",
+                                self.status_breakdown([channel_id], false)
+                            )
+                        }
+                        InviteCheckChannelStatus::Invalid => {
+                            format!(
+                                "🔴 <#{channel_id}> - **{total}** total (**{invalid}** invalid: {})",
+                                self.status_breakdown([channel_id], true)
+                            )
+                        }
+                        InviteCheckChannelStatus::BreaksRules => {
+                            format!("🟠 <#{channel_id}> - **{total}** total (breaking partner rules)")
+                        }
+                        InviteCheckChannelStatus::Temporary => {
+                            format!(
+                                "🟡 <#{channel_id}> - **{total}** total (**{temporary}** temporary)"
+                            )
+                        }
+                        InviteCheckChannelStatus::Valid => {
+                            format!("🟢 <#{channel_id}> - **{total}** total")
+                        }
+                    };
+                    let location_lines = if channel.status() == InviteCheckChannelStatus::Invalid {
+                        self.invalid_invite_location_lines(channel_id)
+                    } else {
+                        String::new()
+                    };
+                    let violation_lines = self
+                        .rule_violations
+                        .get(&channel_id)
+                        .into_iter()
+                        .flatten()
+                        .map(|violation| format!("\n  - ⚠️ {}", violation.description()))
+                        .collect::<String>();
+                    let owner_line = match self.channel_rules.get(&channel_id) {
+                        // Owners are only mentioned when their channel needs attention
+                        Some(ChannelRule {
+                            owner_id: Some(owner_id),
+                            ..
+                        }) if !violation_lines.is_empty()
+                            || !matches!(
+                                channel.status(),
+                                InviteCheckChannelStatus::Ignored | InviteCheckChannelStatus::Valid
+                            ) =>
+                        {
+                            format!("\n  - Owned by <@{owner_id}>")
+                        }
+                        _ => String::new(),
+                    };
+
+                    format!("{line}{location_lines}{violation_lines}{owner_line}")
+                })
+                .collect::<Vec<String>>()
+        };
+        let mut descriptions: Vec<String> = Vec::new();
+
+        for mut block in channel_blocks {
+            if block.len() > CATEGORY_EMBED_DESCRIPTION_LENGTH {
+                let end = (0 ..= CATEGORY_EMBED_DESCRIPTION_LENGTH - 3)
+                    .rev()
+                    .find(|index| block.is_char_boundary(*index))
+                    .unwrap_or_default();
+
+                block.truncate(end);
+                block.push_str("...");
+            }
+
+            match descriptions.last_mut() {
+                Some(description)
+                    if description.len() + block.len() < CATEGORY_EMBED_DESCRIPTION_LENGTH =>
+                {
+                    description.push('\n');
+                    description.push_str(&block);
+                }
+                _ => descriptions.push(block),
+            }
+        }
+
+        descriptions
+            .into_iter()
+            .enumerate()
+            .map(|(index, description)| {
+                let title = if index == 0 {
+                    format!("The \"{}\" category", category.name)
+                } else {
+                    format!("The \"{}\" category (continued)", category.name)
+                };
+
+                EmbedBuilder::new()
+                    .color(self.embed_color)
+                    .description(description)
+                    .title(title)
+                    .build()
+            })
+            .collect()
+    }
+
+    pub fn duplicate_guilds_embed(&self) -> Option<Embed> {
+        if self.duplicate_guilds.is_empty() {
+            return None;
+        }
+
+        let lines = self
+            .duplicate_guilds
+            .iter()
+            .map(|duplicate_guild| {
+                format!(
+                    "- **{}** (`{}`) - {}",
+                    duplicate_guild.target.name,
+                    duplicate_guild.target.guild_id,
+                    duplicate_guild
+                        .channel_ids
+                        .iter()
+                        .map(|channel_id| format!("<#{channel_id}>"))
+                        .collect::<Vec<String>>()
+                        .join(", ")
+                )
+            })
+            .collect();
+
+        Some(get_list_embed("Duplicate servers", lines, self.embed_color))
+    }
+
+    pub fn invalid_invite_location_lines(
+        &self,
+        channel_id: Id<ChannelMarker>,
+    ) -> String {
+        let Some(invite_locations) = self.invalid_invite_locations.get(&channel_id) else {
+            return String::new();
+        };
+        let mut lines = invite_locations
+            .iter()
+            .take(MAX_INVALID_INVITE_LOCATIONS)
+            .map(|(code, location)| {
+                match location {
+                    Some(location) => format!("\n  - `{code}` in {}", location.description()),
+                    None => format!("\n  - `{code}`"),
+                }
+            })
+            .collect::<String>();
+
+        if invite_locations.len() > MAX_INVALID_INVITE_LOCATIONS {
+            lines.push_str(&format!(
+                "\n  - ...and **{}** more",
+                invite_locations.len() - MAX_INVALID_INVITE_LOCATIONS
+            ));
+        }
+
+        lines
+    }
+
+    pub fn results_embed(&self) -> Embed {
+        let (total_valid, total_invalid, total_unknown, total_temporary) = self.total_invites();
+        let total_invites = total_valid + total_invalid + total_unknown + total_temporary;
+        let denominator = max(total_invites, 1);
+        let checked_channel_ids = self
+            .channels()
+            .filter(|channel| !channel.is_ignored && !channel.is_untracked)
+            .map(|channel| channel.channel_id)
+            .collect::<Vec<Id<ChannelMarker>>>();
+        let invalid_breakdown = self.status_breakdown(checked_channel_ids.iter().copied(), true);
+        let unknown_breakdown = self.status_breakdown(checked_channel_ids.iter().copied(), false);
+        let mut stats = vec![
+            format!(
+                "- **{}** channel(s) checked",
+                self.total_channels().separate_with_commas()
+            ),
+            format!(
+                "- **{}** invite(s) checked",
+                total_invites.separate_with_commas()
+            ),
+            format!(
+                "- **{total_valid}** ({:.2}%) valid invite(s)",
+                (total_valid * 100) as f32 / denominator as f32
+            ),
+        ];
+
+        if self.require_permanent_invites {
+            stats.push(format!(
+                "- **{total_temporary}** ({:.2}%) temporary invite(s)",
+                (total_temporary * 100) as f32 / denominator as f32
+            ));
+        }
+
+        if self.invite_counting_mode != InviteCountingMode::All {
+            stats.push(format!(
+                "- Only invites in the {} of each channel were counted",
+                self.invite_counting_mode.label()
+            ));
+        }
+
+        stats.extend([
+            format!(
+                "- **{total_invalid}** ({:.2}%) invalid invite(s){}",
+                (total_invalid * 100) as f32 / denominator as f32,
+                if invalid_breakdown.is_empty() {
+                    "".to_owned()
+                } else {
+                    format!(": {invalid_breakdown}")
+                }
+            ),
+            format!(
+                "- **{total_unknown}** ({:.2}%) unknown invite(s){}",
+                (total_unknown * 100) as f32 / denominator as f32,
+                if unknown_breakdown.is_empty() {
+                    "".to_owned()
+                } else {
+                    format!(": {unknown_breakdown}")
+                }
+            ),
+            format!(
+                "- **{}** duplicate server(s)",
+                self.duplicate_guilds.len().separate_with_commas()
+            ),
+            format!(
+                "- **{}** partner rule violation(s)",
+                self.rule_violations
+                    .values()
+                    .map(Vec::len)
+                    .sum::<usize>()
+                    .separate_with_commas()
+            ),
+        ]);
+
+        EmbedBuilder::new()
+            .color(self.embed_color)
+            .field(EmbedFieldBuilder::new(
+                "Elapsed time",
+                humanize(
+                    ((self.end_time.unix_timestamp_nanos()
+                        - self.start_time.unix_timestamp_nanos())
+                        / 1_000_000) as u128,
+                ),
+            ))
+            .field(EmbedFieldBuilder::new("Stats", stats.join("\n")))
+            .title(match &self.profile {
+                Some(profile) => format!("Results for `{profile}`"),
+                None => "Results".to_owned(),
+            })
+            .build()
+    }
+
+    pub fn status_breakdown(
+        &self,
+        channel_ids: impl IntoIterator<Item = Id<ChannelMarker>>,
+        invalid: bool,
+    ) -> String {
+        let mut status_counts: Vec<(Option<InviteStatus>, u16)> = Vec::new();
+
+        for channel_id in channel_ids {
+            let Some(channel_status_counts) = self.invite_status_counts.get(&channel_id) else {
+                continue;
+            };
+
+            // Invites that were resolved keep the reason they are invalid, while the rest
+            // are unknown because they have not been (successfully) checked yet
+            for (status, count) in channel_status_counts {
+                if status.is_some_and(|status| !status.is_transient()) != invalid {
+                    continue;
+                }
+
+                match status_counts
+                    .iter_mut()
+                    .find(|(other_status, _)| other_status == status)
+                {
+                    Some((_, total)) => *total += count,
+                    None => status_counts.push((*status, *count)),
+                }
+            }
+        }
+
+        status_counts.sort_unstable_by_key(|(_, count)| Reverse(*count));
+        status_counts
+            .into_iter()
+            .map(|(status, count)| {
+                format!(
+                    "{count} {}",
+                    status.map_or("not checked yet", |status| status.label())
+                )
+            })
+            .collect::<Vec<String>>()
+            .join(", ")
+    }
+
+    pub fn total_channels(&self) -> usize {
+        self.channels().count()
+    }
+
+    pub fn total_invites(&self) -> (u32, u32, u32, u32) {
+        self.channels()
+            .filter(|channel| !channel.is_ignored && !channel.is_untracked)
+            .fold(
+                (0, 0, 0, 0),
+                |(valid, invalid, unknown, temporary), channel| {
+                    (
+                        valid + channel.valid_invites as u32,
+                        invalid + channel.invalid_invites as u32,
+                        unknown + channel.unknown_invites as u32,
+                        temporary + channel.temporary_invites as u32,
+                    )
+                },
+            )
+    }
+}
+
+impl RuleViolation {
+    pub fn description(&self) -> String {
+        match self {
+            Self::BlockedGuild {
+                target,
+            } => format!("**{}** is a blocked server", target.name),
+            Self::DuplicateGuild {
+                channel_ids,
+                target,
+            } => {
+                format!(
+                    "**{}** is also linked in {}",
+                    target.name,
+                    channel_ids
+                        .iter()
+                        .map(|channel_id| format!("<#{channel_id}>"))
+                        .collect::<Vec<String>>()
+                        .join(", ")
+                )
+            }
+            Self::TooFewMembers {
+                minimum_member_count,
+                target,
+            } => {
+                format!(
+                    "**{}** has **{}** members (minimum **{}**)",
+                    target.name,
+                    target
+                        .member_count
+                        .unwrap_or_default()
+                        .separate_with_commas(),
+                    minimum_member_count.separate_with_commas()
+                )
+            }
+            Self::UnexpectedInviteCount {
+                channel_rule,
+                valid_invites,
+            } => {
+                format!(
+                    "**{valid_invites}** valid invite(s), expected {}",
+                    channel_rule.expected_invites().unwrap_or_default()
+                )
+            }
+        }
+    }
+}
+
+fn get_rule_violations(
+    database_guild: &database::Guild,
+    categories: &[CategoryReport],
+    invite_targets: &HashMap<Id<ChannelMarker>, Vec<InviteTarget>>,
+    duplicate_guilds: &[DuplicateGuild],
+    channel_rules: &HashMap<Id<ChannelMarker>, ChannelRule>,
+) -> HashMap<Id<ChannelMarker>, Vec<RuleViolation>> {
+    let channel_ids_by_guild = duplicate_guilds
+        .iter()
+        .map(|duplicate_guild| {
+            (
+                duplicate_guild.target.guild_id,
+                &duplicate_guild.channel_ids,
+            )
+        })
+        .collect::<HashMap<Id<GuildMarker>, &Vec<Id<ChannelMarker>>>>();
+    let mut rule_violations = HashMap::new();
+
+    for channel in categories
+        .iter()
+        .flat_map(|category| category.channels.iter())
+        .filter(|channel| !channel.is_ignored)
+    {
+        let channel_id = channel.channel_id;
+        let mut violations = Vec::new();
+
+        for target in invite_targets.get(&channel_id).into_iter().flatten() {
+            if database_guild.blocked_guild_ids.contains(&target.guild_id) {
+                violations.push(RuleViolation::BlockedGuild {
+                    target: target.clone(),
+                });
+            }
+
+            if let (Some(minimum_member_count), Some(member_count)) =
+                (database_guild.minimum_member_count, target.member_count)
+            {
+                if member_count < minimum_member_count {
+                    violations.push(RuleViolation::TooFewMembers {
+                        minimum_member_count,
+                        target: target.clone(),
+                    });
+                }
+            }
+
+            if !database_guild.allow_duplicate_guilds {
+                let other_channel_ids = channel_ids_by_guild
+                    .get(&target.guild_id)
+                    .into_iter()
+                    .flat_map(|channel_ids| channel_ids.iter())
+                    .filter(|other_channel_id| channel_id.ne(*other_channel_id))
+                    .copied()
+                    .collect::<Vec<Id<ChannelMarker>>>();
+
+                if !other_channel_ids.is_empty() {
+                    violations.push(RuleViolation::DuplicateGuild {
+                        channel_ids: other_channel_ids,
+                        target: target.clone(),
+                    });
+                }
+            }
+        }
+
+        if let Some(channel_rule) = channel_rules.get(&channel_id) {
+            if !channel_rule.is_expected_invite_count(channel.valid_invites) {
+                violations.push(RuleViolation::UnexpectedInviteCount {
+                    channel_rule: channel_rule.clone(),
+                    valid_invites: channel.valid_invites,
+                });
+            }
+        }
+
+        if !violations.is_empty() {
+            rule_violations.insert(channel_id, violations);
+        }
+    }
+
+    rule_violations
+}
+
+fn get_checked_channel_ids(categories: &[CategoryReport]) -> Vec<Id<ChannelMarker>> {
+    categories
+        .iter()
+        .flat_map(|category| category.channels.iter())
+        .filter(|channel| !channel.is_ignored)
+        .map(|channel| channel.channel_id)
+        .collect()
+}
+
+fn get_duplicate_guilds(
+    categories: &[CategoryReport],
+    invite_targets: &HashMap<Id<ChannelMarker>, Vec<InviteTarget>>,
+) -> Vec<DuplicateGuild> {
+    let mut duplicate_guilds: HashMap<Id<GuildMarker>, DuplicateGuild> = HashMap::new();
+
+    for channel_id in get_checked_channel_ids(categories) {
+        for target in invite_targets.get(&channel_id).into_iter().flatten() {
+            duplicate_guilds
+                .entry(target.guild_id)
+                .or_insert_with(|| {
+                    DuplicateGuild {
+                        channel_ids: Vec::new(),
+                        target: target.clone(),
+                    }
+                })
+                .channel_ids
+                .push(channel_id);
+        }
+    }
+
+    // Only servers that are linked in more than one channel are duplicates
+    let mut duplicate_guilds = duplicate_guilds
+        .into_values()
+        .filter(|duplicate_guild| duplicate_guild.channel_ids.len() > 1)
+        .collect::<Vec<DuplicateGuild>>();
+
+    duplicate_guilds.sort_unstable_by(|a, b| {
+        b.channel_ids
+            .len()
+            .cmp(&a.channel_ids.len())
+            .then_with(|| a.target.name.cmp(&b.target.name))
+    });
+
+    duplicate_guilds
+}
+
+fn get_channel_changes(
+    previous_channels: &[InviteCheckChannel],
+    report: &CheckReport,
+) -> Vec<ChannelChange> {
+    let mut previous_statuses = previous_channels
+        .iter()
+        .map(|channel| (channel.channel_id, channel.status()))
+        .collect::<HashMap<Id<ChannelMarker>, InviteCheckChannelStatus>>();
+    let mut changes = Vec::new();
+
+    for channel in report.channels() {
+        let status = channel.status();
+        let previous_status = previous_statuses.remove(&channel.channel_id);
+
+        if previous_status != Some(status) {
+            changes.push(ChannelChange {
+                channel_id: channel.channel_id,
+                previous_status,
+                status: Some(status),
+            });
+        }
+    }
+
+    for channel in previous_channels {
+        if let Some(previous_status) = previous_statuses.get(&channel.channel_id) {
+            changes.push(ChannelChange {
+                channel_id: channel.channel_id,
+                previous_status: Some(*previous_status),
+                status: None,
+            });
+        }
+    }
+
+    changes
+}
+
+fn get_diff_embeds(
+    changes: &[ChannelChange],
+    color: u32,
+) -> Vec<Embed> {
+    let mut newly_invalid = Vec::new();
+    let mut fixed = Vec::new();
+    let mut changed = Vec::new();
+    let mut added = Vec::new();
+    let mut removed = Vec::new();
+
+    for change in changes {
+        let channel_id = change.channel_id;
+
+        match (change.previous_status, change.status) {
+            (None, Some(status)) => {
+                added.push(format!(
+                    "{} <#{channel_id}> - **{}**",
+                    status.emoji(),
+                    status.label()
+                ))
+            }
+            (Some(previous_status), None) => {
+                removed.push(format!(
+                    "⚪ <#{channel_id}> - previously **{}**",
+                    previous_status.label()
+                ))
+            }
+            (Some(previous_status), Some(status)) => {
+                let line = format!(
+                    "{} <#{channel_id}> - **{}** (previously {})",
+                    status.emoji(),
+                    status.label(),
+                    previous_status.label()
+                );
+
+                match status {
+                    InviteCheckChannelStatus::Invalid => newly_invalid.push(line),
+                    InviteCheckChannelStatus::Valid => fixed.push(line),
+                    _ => changed.push(line),
+                }
+            }
+            (None, None) => {}
+        }
+    }
+
+    let sections = [
+        ("Newly invalid", newly_invalid),
+        ("Fixed", fixed),
+        ("Other changes", changed),
+        ("Added", added),
+        ("Removed", removed),
+    ];
+    let mut embeds = sections
+        .into_iter()
+        .filter(|(_, lines)| !lines.is_empty())
+        .map(|(title, lines)| get_list_embed(title, lines, color))
+        .collect::<Vec<Embed>>();
+
+    if embeds.is_empty() {
+        embeds.push(
+            EmbedBuilder::new()
+                .color(color)
+                .description("No channels have changed since the last invite check.")
+                .build(),
+        );
+    }
+
+    embeds
+}
@@ -1,12 +1,19 @@
-use std::{collections::HashMap, sync::Arc};
+use std::{
+    collections::HashMap,
+    sync::{atomic::AtomicBool, Arc},
+};
 
 use parking_lot::RwLock;
 use time::OffsetDateTime;
+use tokio_cron_scheduler::JobScheduler;
 use twilight_gateway::Latency;
 use twilight_http::client::{Client, InteractionClient};
 use twilight_model::id::{marker::ApplicationMarker, Id};
 
-use crate::types::{cache::Cache, context::Context, database::Database};
+use crate::{
+    types::{cache::Cache, context::Context, database::Database, shortener::InviteShortener},
+    utility::constants::INVITE_SHORTENER_DOMAINS,
+};
 
 impl Context {
     pub fn interaction_client(&self) -> InteractionClient<'_> {
@@ -18,14 +25,21 @@ impl Context {
         cache: Cache,
         database: Database,
         http: Client,
+        scheduler: JobScheduler,
     ) -> Self {
         Self {
             application_id,
             cache,
             database,
             http: Arc::new(http),
+            invite_rate_limited: AtomicBool::new(false),
             latencies: RwLock::new(HashMap::new()),
+            pending_alerts: RwLock::new(HashMap::new()),
             ready_at: RwLock::new(None),
+            scheduled_checks: RwLock::new(HashMap::new()),
+            scheduler,
+            shortener: (!INVITE_SHORTENER_DOMAINS.is_empty())
+                .then(|| InviteShortener::new(INVITE_SHORTENER_DOMAINS.clone())),
         }
     }
 
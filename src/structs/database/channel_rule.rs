@@ -0,0 +1,249 @@
+use std::collections::HashMap;
+
+use tokio_postgres::{types::ToSql, Row};
+use twilight_model::id::{
+    marker::{ChannelMarker, GuildMarker, UserMarker},
+    Id,
+};
+
+use crate::types::{
+    database::{ChannelRule, Database},
+    Result,
+};
+
+impl Database {
+    // Rules without a profile only apply to the guild's own invite checks
+    pub async fn get_channel_rules(
+        &self,
+        guild_id: Id<GuildMarker>,
+        profile: Option<&str>,
+    ) -> Result<HashMap<Id<ChannelMarker>, ChannelRule>> {
+        let client = self.pool.get().await?;
+        let statement = "
+            SELECT
+                *
+            FROM
+                public.channel_rule
+            WHERE
+                guild_id = $1
+                AND profile IS NOT DISTINCT FROM $2;
+        ";
+        let params: &[&(dyn ToSql + Sync)] = &[&(guild_id.get() as i64), &profile];
+        let channel_rules = client
+            .query(statement, params)
+            .await?
+            .into_iter()
+            .map(|row| {
+                let channel_rule = ChannelRule::from(row);
+
+                (channel_rule.channel_id, channel_rule)
+            })
+            .collect();
+
+        Ok(channel_rules)
+    }
+
+    pub async fn insert_channel_allow_temporary_invites(
+        &self,
+        guild_id: Id<GuildMarker>,
+        profile: Option<&str>,
+        channel_id: Id<ChannelMarker>,
+        allow_temporary_invites: bool,
+    ) -> Result<()> {
+        let client = self.pool.get().await?;
+        let statement = "
+            INSERT INTO
+                public.channel_rule (guild_id, profile, channel_id, allow_temporary_invites)
+            VALUES
+                ($1, $2, $3, $4)
+            ON CONFLICT (guild_id, profile_key, channel_id)
+            DO UPDATE
+            SET
+                allow_temporary_invites = EXCLUDED.allow_temporary_invites;
+        ";
+        let params: &[&(dyn ToSql + Sync)] = &[
+            &(guild_id.get() as i64),
+            &profile,
+            &(channel_id.get() as i64),
+            &allow_temporary_invites,
+        ];
+
+        client.execute(statement, params).await?;
+
+        Ok(())
+    }
+
+    pub async fn insert_channel_expected_invites(
+        &self,
+        guild_id: Id<GuildMarker>,
+        profile: Option<&str>,
+        channel_id: Id<ChannelMarker>,
+        minimum_invites: Option<i32>,
+        maximum_invites: Option<i32>,
+    ) -> Result<()> {
+        let client = self.pool.get().await?;
+        let statement = "
+            INSERT INTO
+                public.channel_rule (guild_id, profile, channel_id, minimum_invites, maximum_invites)
+            VALUES
+                ($1, $2, $3, $4, $5)
+            ON CONFLICT (guild_id, profile_key, channel_id)
+            DO UPDATE
+            SET
+                minimum_invites = EXCLUDED.minimum_invites,
+                maximum_invites = EXCLUDED.maximum_invites;
+        ";
+        let params: &[&(dyn ToSql + Sync)] = &[
+            &(guild_id.get() as i64),
+            &profile,
+            &(channel_id.get() as i64),
+            &minimum_invites,
+            &maximum_invites,
+        ];
+
+        client.execute(statement, params).await?;
+
+        Ok(())
+    }
+
+    pub async fn insert_channel_owner(
+        &self,
+        guild_id: Id<GuildMarker>,
+        profile: Option<&str>,
+        channel_id: Id<ChannelMarker>,
+        owner_id: Option<Id<UserMarker>>,
+    ) -> Result<()> {
+        let client = self.pool.get().await?;
+        let statement = "
+            INSERT INTO
+                public.channel_rule (guild_id, profile, channel_id, owner_id)
+            VALUES
+                ($1, $2, $3, $4)
+            ON CONFLICT (guild_id, profile_key, channel_id)
+            DO UPDATE
+            SET
+                owner_id = EXCLUDED.owner_id;
+        ";
+        let params: &[&(dyn ToSql + Sync)] = &[
+            &(guild_id.get() as i64),
+            &profile,
+            &(channel_id.get() as i64),
+            &owner_id.map(|owner_id| owner_id.get() as i64),
+        ];
+
+        client.execute(statement, params).await?;
+
+        Ok(())
+    }
+
+    pub async fn remove_channel_rule(
+        &self,
+        guild_id: Id<GuildMarker>,
+        profile: Option<&str>,
+        channel_id: Id<ChannelMarker>,
+    ) -> Result<bool> {
+        let client = self.pool.get().await?;
+        let statement = "
+            DELETE FROM
+                public.channel_rule
+            WHERE
+                guild_id = $1
+                AND profile IS NOT DISTINCT FROM $2
+                AND channel_id = $3;
+        ";
+        let params: &[&(dyn ToSql + Sync)] =
+            &[&(guild_id.get() as i64), &profile, &(channel_id.get() as i64)];
+        let removed_rows = client.execute(statement, params).await?;
+
+        Ok(removed_rows > 0)
+    }
+
+    // Forgets a deleted channel in the rules of the guild and every profile
+    pub async fn remove_channel_rules(
+        &self,
+        guild_id: Id<GuildMarker>,
+        channel_id: Id<ChannelMarker>,
+    ) -> Result<()> {
+        let client = self.pool.get().await?;
+        let statement = "
+            DELETE FROM
+                public.channel_rule
+            WHERE
+                guild_id = $1
+                AND channel_id = $2;
+        ";
+        let params: &[&(dyn ToSql + Sync)] =
+            &[&(guild_id.get() as i64), &(channel_id.get() as i64)];
+
+        client.execute(statement, params).await?;
+
+        Ok(())
+    }
+}
+
+impl ChannelRule {
+    pub fn description(&self) -> String {
+        let mut parts = Vec::new();
+
+        if let Some(expected_invites) = self.expected_invites() {
+            parts.push(format!("expects {expected_invites} valid invite(s)"));
+        }
+
+        if self.allow_temporary_invites {
+            parts.push("allows temporary invites".to_owned());
+        }
+
+        if let Some(owner_id) = self.owner_id {
+            parts.push(format!("owned by <@{owner_id}>"));
+        }
+
+        if parts.is_empty() {
+            "no expectations".to_owned()
+        } else {
+            parts.join(", ")
+        }
+    }
+
+    pub fn expected_invites(&self) -> Option<String> {
+        match (self.minimum_invites, self.maximum_invites) {
+            (Some(minimum_invites), Some(maximum_invites))
+                if minimum_invites == maximum_invites =>
+            {
+                Some(format!("exactly **{minimum_invites}**"))
+            }
+            (Some(minimum_invites), Some(maximum_invites)) => {
+                Some(format!(
+                    "between **{minimum_invites}** and **{maximum_invites}**"
+                ))
+            }
+            (Some(minimum_invites), None) => Some(format!("at least **{minimum_invites}**")),
+            (None, Some(maximum_invites)) => Some(format!("at most **{maximum_invites}**")),
+            (None, None) => None,
+        }
+    }
+
+    pub fn is_expected_invite_count(
+        &self,
+        valid_invites: i32,
+    ) -> bool {
+        self.minimum_invites
+            .is_none_or(|minimum_invites| valid_invites >= minimum_invites)
+            && self
+                .maximum_invites
+                .is_none_or(|maximum_invites| valid_invites <= maximum_invites)
+    }
+}
+
+impl From<Row> for ChannelRule {
+    fn from(row: Row) -> Self {
+        Self {
+            channel_id: Id::new(row.get::<_, i64>("channel_id") as u64),
+            minimum_invites: row.get::<_, Option<i32>>("minimum_invites"),
+            maximum_invites: row.get::<_, Option<i32>>("maximum_invites"),
+            allow_temporary_invites: row.get::<_, bool>("allow_temporary_invites"),
+            owner_id: row
+                .get::<_, Option<i64>>("owner_id")
+                .map(|owner_id| Id::new(owner_id as u64)),
+        }
+    }
+}
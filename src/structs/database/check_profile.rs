@@ -0,0 +1,663 @@
+use std::collections::HashSet;
+
+use serde_json::Value;
+use tokio_postgres::{types::ToSql, Row};
+use twilight_model::id::{
+    marker::{ChannelMarker, GuildMarker},
+    Id,
+};
+
+use crate::types::{
+    database::{CheckProfile, Database, Guild, IgnoreRule},
+    Result,
+};
+
+impl Database {
+    pub async fn get_check_profile(
+        &self,
+        guild_id: Id<GuildMarker>,
+        name: &str,
+    ) -> Result<Option<CheckProfile>> {
+        let client = self.pool.get().await?;
+        let statement = "
+            SELECT
+                *
+            FROM
+                public.check_profile
+            WHERE
+                guild_id = $1
+                AND name = $2;
+        ";
+        let params: &[&(dyn ToSql + Sync)] = &[&(guild_id.get() as i64), &name];
+        let check_profile = client.query_opt(statement, params).await?.map(Into::into);
+
+        Ok(check_profile)
+    }
+
+    // Messages in these categories are stored even if the guild itself does not
+    // check them
+    pub async fn get_check_profile_category_channel_ids(
+        &self,
+        guild_id: Id<GuildMarker>,
+    ) -> Result<HashSet<Id<ChannelMarker>>> {
+        let client = self.pool.get().await?;
+        let statement = "
+            SELECT DISTINCT
+                UNNEST(category_channel_ids) AS category_channel_id
+            FROM
+                public.check_profile
+            WHERE
+                guild_id = $1;
+        ";
+        let params: &[&(dyn ToSql + Sync)] = &[&(guild_id.get() as i64)];
+        let category_channel_ids = client
+            .query(statement, params)
+            .await?
+            .into_iter()
+            .map(|row| Id::new(row.get::<_, i64>("category_channel_id") as u64))
+            .collect();
+
+        Ok(category_channel_ids)
+    }
+
+    pub async fn get_check_profile_schedules(
+        &self
+    ) -> Result<Vec<(Id<GuildMarker>, String, String)>> {
+        let client = self.pool.get().await?;
+        let statement = "
+            SELECT
+                guild_id,
+                name,
+                check_schedule
+            FROM
+                public.check_profile
+            WHERE
+                check_schedule IS NOT NULL;
+        ";
+        let params: &[&(dyn ToSql + Sync)] = &[];
+        let check_schedules = client
+            .query(statement, params)
+            .await?
+            .into_iter()
+            .map(|row| {
+                (
+                    Id::new(row.get::<_, i64>("guild_id") as u64),
+                    row.get::<_, String>("name"),
+                    row.get::<_, String>("check_schedule"),
+                )
+            })
+            .collect();
+
+        Ok(check_schedules)
+    }
+
+    pub async fn get_check_profiles(
+        &self,
+        guild_id: Id<GuildMarker>,
+    ) -> Result<Vec<CheckProfile>> {
+        let client = self.pool.get().await?;
+        let statement = "
+            SELECT
+                *
+            FROM
+                public.check_profile
+            WHERE
+                guild_id = $1
+            ORDER BY
+                name;
+        ";
+        let params: &[&(dyn ToSql + Sync)] = &[&(guild_id.get() as i64)];
+        let check_profiles = client
+            .query(statement, params)
+            .await?
+            .into_iter()
+            .map(Into::into)
+            .collect();
+
+        Ok(check_profiles)
+    }
+
+    // Messages in these channels are stored even if the guild itself does not
+    // track them
+    pub async fn get_check_profile_tracked_channel_ids(
+        &self,
+        guild_id: Id<GuildMarker>,
+    ) -> Result<HashSet<Id<ChannelMarker>>> {
+        let client = self.pool.get().await?;
+        let statement = "
+            SELECT DISTINCT
+                UNNEST(tracked_channel_ids) AS tracked_channel_id
+            FROM
+                public.check_profile
+            WHERE
+                guild_id = $1;
+        ";
+        let params: &[&(dyn ToSql + Sync)] = &[&(guild_id.get() as i64)];
+        let tracked_channel_ids = client
+            .query(statement, params)
+            .await?
+            .into_iter()
+            .map(|row| Id::new(row.get::<_, i64>("tracked_channel_id") as u64))
+            .collect();
+
+        Ok(tracked_channel_ids)
+    }
+
+    pub async fn insert_check_profile(
+        &self,
+        guild_id: Id<GuildMarker>,
+        name: &str,
+    ) -> Result<bool> {
+        let client = self.pool.get().await?;
+        let statement = "
+            INSERT INTO
+                public.check_profile (guild_id, name)
+            VALUES
+                ($1, $2)
+            ON CONFLICT (guild_id, name)
+            DO NOTHING;
+        ";
+        let params: &[&(dyn ToSql + Sync)] = &[&(guild_id.get() as i64), &name];
+        let inserted_rows = client.execute(statement, params).await?;
+
+        Ok(inserted_rows > 0)
+    }
+
+    pub async fn insert_check_profile_blocked_guild(
+        &self,
+        guild_id: Id<GuildMarker>,
+        name: &str,
+        blocked_guild_id: Id<GuildMarker>,
+    ) -> Result<bool> {
+        let client = self.pool.get().await?;
+        let statement = "
+            UPDATE
+                public.check_profile
+            SET
+                blocked_guild_ids = ARRAY(
+                    SELECT DISTINCT UNNEST(ARRAY_APPEND(blocked_guild_ids, $3))
+                )
+            WHERE
+                guild_id = $1
+                AND name = $2;
+        ";
+        let params: &[&(dyn ToSql + Sync)] =
+            &[&(guild_id.get() as i64), &name, &(blocked_guild_id.get() as i64)];
+        let updated_rows = client.execute(statement, params).await?;
+
+        Ok(updated_rows > 0)
+    }
+
+    pub async fn insert_check_profile_category_channel(
+        &self,
+        guild_id: Id<GuildMarker>,
+        name: &str,
+        channel_id: Id<ChannelMarker>,
+    ) -> Result<bool> {
+        let client = self.pool.get().await?;
+        let statement = "
+            UPDATE
+                public.check_profile
+            SET
+                category_channel_ids = ARRAY(
+                    SELECT DISTINCT UNNEST(ARRAY_APPEND(category_channel_ids, $3))
+                )
+            WHERE
+                guild_id = $1
+                AND name = $2;
+        ";
+        let params: &[&(dyn ToSql + Sync)] =
+            &[&(guild_id.get() as i64), &name, &(channel_id.get() as i64)];
+        let updated_rows = client.execute(statement, params).await?;
+
+        Ok(updated_rows > 0)
+    }
+
+    pub async fn insert_check_profile_embed_color(
+        &self,
+        guild_id: Id<GuildMarker>,
+        name: &str,
+        embed_color: i32,
+    ) -> Result<bool> {
+        let client = self.pool.get().await?;
+        let statement = "
+            UPDATE
+                public.check_profile
+            SET
+                embed_color = $3
+            WHERE
+                guild_id = $1
+                AND name = $2;
+        ";
+        let params: &[&(dyn ToSql + Sync)] = &[&(guild_id.get() as i64), &name, &embed_color];
+        let updated_rows = client.execute(statement, params).await?;
+
+        Ok(updated_rows > 0)
+    }
+
+    pub async fn insert_check_profile_ignore_rule(
+        &self,
+        guild_id: Id<GuildMarker>,
+        name: &str,
+        ignore_rule: &IgnoreRule,
+    ) -> Result<bool> {
+        let client = self.pool.get().await?;
+        let statement = "
+            UPDATE
+                public.check_profile
+            SET
+                ignore_rules = CASE
+                    WHEN ignore_rules @> $3 THEN ignore_rules
+                    ELSE ignore_rules || $3
+                END
+            WHERE
+                guild_id = $1
+                AND name = $2;
+        ";
+        let params: &[&(dyn ToSql + Sync)] =
+            &[&(guild_id.get() as i64), &name, &serde_json::to_value([ignore_rule])?];
+        let updated_rows = client.execute(statement, params).await?;
+
+        Ok(updated_rows > 0)
+    }
+
+    pub async fn insert_check_profile_ignored_channel(
+        &self,
+        guild_id: Id<GuildMarker>,
+        name: &str,
+        channel_id: Id<ChannelMarker>,
+    ) -> Result<bool> {
+        let client = self.pool.get().await?;
+        let statement = "
+            UPDATE
+                public.check_profile
+            SET
+                ignored_channel_ids = ARRAY(
+                    SELECT DISTINCT UNNEST(ARRAY_APPEND(ignored_channel_ids, $3))
+                )
+            WHERE
+                guild_id = $1
+                AND name = $2;
+        ";
+        let params: &[&(dyn ToSql + Sync)] =
+            &[&(guild_id.get() as i64), &name, &(channel_id.get() as i64)];
+        let updated_rows = client.execute(statement, params).await?;
+
+        Ok(updated_rows > 0)
+    }
+
+    pub async fn insert_check_profile_results_channel(
+        &self,
+        guild_id: Id<GuildMarker>,
+        name: &str,
+        channel_id: Id<ChannelMarker>,
+    ) -> Result<bool> {
+        let client = self.pool.get().await?;
+        let statement = "
+            UPDATE
+                public.check_profile
+            SET
+                results_channel_id = $3
+            WHERE
+                guild_id = $1
+                AND name = $2;
+        ";
+        let params: &[&(dyn ToSql + Sync)] =
+            &[&(guild_id.get() as i64), &name, &(channel_id.get() as i64)];
+        let updated_rows = client.execute(statement, params).await?;
+
+        Ok(updated_rows > 0)
+    }
+
+    pub async fn insert_check_profile_rules(
+        &self,
+        guild_id: Id<GuildMarker>,
+        name: &str,
+        minimum_member_count: Option<i32>,
+        allow_duplicate_guilds: bool,
+        require_permanent_invites: bool,
+    ) -> Result<bool> {
+        let client = self.pool.get().await?;
+        let statement = "
+            UPDATE
+                public.check_profile
+            SET
+                minimum_member_count = $3,
+                allow_duplicate_guilds = $4,
+                require_permanent_invites = $5
+            WHERE
+                guild_id = $1
+                AND name = $2;
+        ";
+        let params: &[&(dyn ToSql + Sync)] = &[
+            &(guild_id.get() as i64),
+            &name,
+            &minimum_member_count,
+            &allow_duplicate_guilds,
+            &require_permanent_invites,
+        ];
+        let updated_rows = client.execute(statement, params).await?;
+
+        Ok(updated_rows > 0)
+    }
+
+    pub async fn insert_check_profile_schedule(
+        &self,
+        guild_id: Id<GuildMarker>,
+        name: &str,
+        check_schedule: &str,
+    ) -> Result<bool> {
+        let client = self.pool.get().await?;
+        let statement = "
+            UPDATE
+                public.check_profile
+            SET
+                check_schedule = $3
+            WHERE
+                guild_id = $1
+                AND name = $2;
+        ";
+        let params: &[&(dyn ToSql + Sync)] = &[&(guild_id.get() as i64), &name, &check_schedule];
+        let updated_rows = client.execute(statement, params).await?;
+
+        Ok(updated_rows > 0)
+    }
+
+    pub async fn insert_check_profile_tracked_channel(
+        &self,
+        guild_id: Id<GuildMarker>,
+        name: &str,
+        channel_id: Id<ChannelMarker>,
+    ) -> Result<bool> {
+        let client = self.pool.get().await?;
+        let statement = "
+            UPDATE
+                public.check_profile
+            SET
+                tracked_channel_ids = ARRAY(
+                    SELECT DISTINCT UNNEST(ARRAY_APPEND(tracked_channel_ids, $3))
+                )
+            WHERE
+                guild_id = $1
+                AND name = $2;
+        ";
+        let params: &[&(dyn ToSql + Sync)] =
+            &[&(guild_id.get() as i64), &name, &(channel_id.get() as i64)];
+        let updated_rows = client.execute(statement, params).await?;
+
+        Ok(updated_rows > 0)
+    }
+
+    pub async fn remove_check_profile(
+        &self,
+        guild_id: Id<GuildMarker>,
+        name: &str,
+    ) -> Result<bool> {
+        let client = self.pool.get().await?;
+        let statement = "
+            DELETE FROM
+                public.check_profile
+            WHERE
+                guild_id = $1
+                AND name = $2;
+        ";
+        let params: &[&(dyn ToSql + Sync)] = &[&(guild_id.get() as i64), &name];
+        let deleted_rows = client.execute(statement, params).await?;
+
+        Ok(deleted_rows > 0)
+    }
+
+    pub async fn remove_check_profile_blocked_guild(
+        &self,
+        guild_id: Id<GuildMarker>,
+        name: &str,
+        blocked_guild_id: Id<GuildMarker>,
+    ) -> Result<bool> {
+        let client = self.pool.get().await?;
+        let statement = "
+            UPDATE
+                public.check_profile
+            SET
+                blocked_guild_ids = ARRAY_REMOVE(blocked_guild_ids, $3)
+            WHERE
+                guild_id = $1
+                AND name = $2;
+        ";
+        let params: &[&(dyn ToSql + Sync)] =
+            &[&(guild_id.get() as i64), &name, &(blocked_guild_id.get() as i64)];
+        let updated_rows = client.execute(statement, params).await?;
+
+        Ok(updated_rows > 0)
+    }
+
+    pub async fn remove_check_profile_category_channel(
+        &self,
+        guild_id: Id<GuildMarker>,
+        name: &str,
+        channel_id: Id<ChannelMarker>,
+    ) -> Result<bool> {
+        let client = self.pool.get().await?;
+        let statement = "
+            UPDATE
+                public.check_profile
+            SET
+                category_channel_ids = ARRAY_REMOVE(category_channel_ids, $3)
+            WHERE
+                guild_id = $1
+                AND name = $2;
+        ";
+        let params: &[&(dyn ToSql + Sync)] =
+            &[&(guild_id.get() as i64), &name, &(channel_id.get() as i64)];
+        let updated_rows = client.execute(statement, params).await?;
+
+        Ok(updated_rows > 0)
+    }
+
+    // Forgets a deleted channel in every profile of the guild
+    pub async fn remove_check_profile_channel(
+        &self,
+        guild_id: Id<GuildMarker>,
+        channel_id: Id<ChannelMarker>,
+    ) -> Result<()> {
+        let client = self.pool.get().await?;
+        let statement = "
+            UPDATE
+                public.check_profile
+            SET
+                category_channel_ids = ARRAY_REMOVE(category_channel_ids, $2),
+                ignored_channel_ids = ARRAY_REMOVE(ignored_channel_ids, $2),
+                tracked_channel_ids = ARRAY_REMOVE(tracked_channel_ids, $2),
+                results_channel_id = CASE
+                    WHEN results_channel_id = $2 THEN NULL
+                    ELSE results_channel_id
+                END
+            WHERE
+                guild_id = $1;
+        ";
+        let params: &[&(dyn ToSql + Sync)] =
+            &[&(guild_id.get() as i64), &(channel_id.get() as i64)];
+
+        client.execute(statement, params).await?;
+
+        Ok(())
+    }
+
+    pub async fn remove_check_profile_ignore_rule(
+        &self,
+        guild_id: Id<GuildMarker>,
+        name: &str,
+        ignore_rule: &IgnoreRule,
+    ) -> Result<bool> {
+        let client = self.pool.get().await?;
+        let statement = "
+            UPDATE
+                public.check_profile
+            SET
+                ignore_rules = (
+                    SELECT
+                        COALESCE(JSONB_AGG(ignore_rule), '[]')
+                    FROM
+                        JSONB_ARRAY_ELEMENTS(ignore_rules) ignore_rule
+                    WHERE
+                        ignore_rule <> $3
+                )
+            WHERE
+                guild_id = $1
+                AND name = $2;
+        ";
+        let params: &[&(dyn ToSql + Sync)] =
+            &[&(guild_id.get() as i64), &name, &serde_json::to_value(ignore_rule)?];
+        let updated_rows = client.execute(statement, params).await?;
+
+        Ok(updated_rows > 0)
+    }
+
+    pub async fn remove_check_profile_ignored_channel(
+        &self,
+        guild_id: Id<GuildMarker>,
+        name: &str,
+        channel_id: Id<ChannelMarker>,
+    ) -> Result<bool> {
+        let client = self.pool.get().await?;
+        let statement = "
+            UPDATE
+                public.check_profile
+            SET
+                ignored_channel_ids = ARRAY_REMOVE(ignored_channel_ids, $3)
+            WHERE
+                guild_id = $1
+                AND name = $2;
+        ";
+        let params: &[&(dyn ToSql + Sync)] =
+            &[&(guild_id.get() as i64), &name, &(channel_id.get() as i64)];
+        let updated_rows = client.execute(statement, params).await?;
+
+        Ok(updated_rows > 0)
+    }
+
+    pub async fn remove_check_profile_schedule(
+        &self,
+        guild_id: Id<GuildMarker>,
+        name: &str,
+    ) -> Result<bool> {
+        let client = self.pool.get().await?;
+        let statement = "
+            UPDATE
+                public.check_profile
+            SET
+                check_schedule = NULL
+            WHERE
+                guild_id = $1
+                AND name = $2;
+        ";
+        let params: &[&(dyn ToSql + Sync)] = &[&(guild_id.get() as i64), &name];
+        let updated_rows = client.execute(statement, params).await?;
+
+        Ok(updated_rows > 0)
+    }
+
+    pub async fn remove_check_profile_tracked_channel(
+        &self,
+        guild_id: Id<GuildMarker>,
+        name: &str,
+        channel_id: Id<ChannelMarker>,
+    ) -> Result<bool> {
+        let client = self.pool.get().await?;
+        let statement = "
+            UPDATE
+                public.check_profile
+            SET
+                tracked_channel_ids = ARRAY_REMOVE(tracked_channel_ids, $3)
+            WHERE
+                guild_id = $1
+                AND name = $2;
+        ";
+        let params: &[&(dyn ToSql + Sync)] =
+            &[&(guild_id.get() as i64), &name, &(channel_id.get() as i64)];
+        let updated_rows = client.execute(statement, params).await?;
+
+        Ok(updated_rows > 0)
+    }
+}
+
+impl CheckProfile {
+    pub fn description(&self) -> String {
+        let results_channel = match self.results_channel_id {
+            Some(results_channel_id) => format!("results in <#{results_channel_id}>"),
+            None => "no results channel".to_owned(),
+        };
+        let check_schedule = match &self.check_schedule {
+            Some(check_schedule) => format!("runs on `{check_schedule}`"),
+            None => "not scheduled".to_owned(),
+        };
+
+        format!(
+            "**{}** - {} category(s), {} tracked channel(s), {results_channel}, {check_schedule}",
+            self.name,
+            self.category_channel_ids.len(),
+            self.tracked_channel_ids.len()
+        )
+    }
+}
+
+impl Guild {
+    // A profile replaces what is checked and how it is graded, while scanning
+    // settings stay shared across the guild
+    pub fn with_check_profile(
+        self,
+        check_profile: CheckProfile,
+    ) -> Self {
+        Self {
+            category_channel_ids: check_profile.category_channel_ids,
+            ignored_channel_ids: check_profile.ignored_channel_ids,
+            embed_color: check_profile.embed_color,
+            results_channel_id: check_profile.results_channel_id,
+            check_schedule: check_profile.check_schedule,
+            minimum_member_count: check_profile.minimum_member_count,
+            allow_duplicate_guilds: check_profile.allow_duplicate_guilds,
+            require_permanent_invites: check_profile.require_permanent_invites,
+            blocked_guild_ids: check_profile.blocked_guild_ids,
+            ignore_rules: check_profile.ignore_rules,
+            tracked_channel_ids: check_profile.tracked_channel_ids,
+            ..self
+        }
+    }
+}
+
+impl From<Row> for CheckProfile {
+    fn from(row: Row) -> Self {
+        Self {
+            name: row.get::<_, String>("name"),
+            category_channel_ids: row
+                .get::<_, Vec<i64>>("category_channel_ids")
+                .into_iter()
+                .map(|id| Id::new(id as u64))
+                .collect(),
+            ignored_channel_ids: row
+                .get::<_, Vec<i64>>("ignored_channel_ids")
+                .into_iter()
+                .map(|id| Id::new(id as u64))
+                .collect(),
+            embed_color: row.get::<_, i32>("embed_color"),
+            results_channel_id: row
+                .get::<_, Option<i64>>("results_channel_id")
+                .map(|channel_id| Id::new(channel_id as u64)),
+            check_schedule: row.get::<_, Option<String>>("check_schedule"),
+            minimum_member_count: row.get::<_, Option<i32>>("minimum_member_count"),
+            allow_duplicate_guilds: row.get::<_, bool>("allow_duplicate_guilds"),
+            require_permanent_invites: row.get::<_, bool>("require_permanent_invites"),
+            blocked_guild_ids: row
+                .get::<_, Vec<i64>>("blocked_guild_ids")
+                .into_iter()
+                .map(|id| Id::new(id as u64))
+                .collect(),
+            ignore_rules: serde_json::from_value(row.get::<_, Value>("ignore_rules"))
+                .unwrap_or_default(),
+            tracked_channel_ids: row
+                .get::<_, Vec<i64>>("tracked_channel_ids")
+                .into_iter()
+                .map(|id| Id::new(id as u64))
+                .collect(),
+        }
+    }
+}
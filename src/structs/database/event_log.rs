@@ -1,38 +1,106 @@
+use deadpool_postgres::GenericClient;
 use serde::Serialize;
+use serde_json::Value;
 use tokio_postgres::types::ToSql;
+use twilight_model::id::{marker::GuildMarker, Id};
 
 use crate::types::{
-    database::{Database, Event, GuildCreatePayload, GuildDeletePayload, InviteCheckCreatePayload},
+    database::{
+        Database,
+        Event,
+        GuildCreatePayload,
+        GuildDeletePayload,
+        InviteCheckChannel,
+        InviteCheckCreatePayload,
+    },
     Result,
 };
 
 impl Database {
-    async fn insert_event(
+    pub async fn get_invite_check_count(
         &self,
-        event: Event,
-        payload: impl Serialize,
-    ) -> Result<()> {
+        guild_id: Id<GuildMarker>,
+    ) -> Result<i64> {
         let client = self.pool.get().await?;
 
+        let statement = "
+            SELECT
+                COUNT(*) AS invite_checks
+            FROM
+                public.event_log
+            WHERE
+                event_type = 'INVITE_CHECK_CREATE'
+                AND (payload->>'guild_id')::INT8 = $1;
+        ";
+
+        let params: &[&(dyn ToSql + Sync)] = &[&(guild_id.get() as i64)];
+        let row = client.query_one(statement, params).await?;
+
+        Ok(row.get::<_, i64>("invite_checks"))
+    }
+
+    pub async fn get_invite_checks(
+        &self,
+        guild_id: Id<GuildMarker>,
+        limit: i64,
+        offset: i64,
+    ) -> Result<Vec<InviteCheckCreatePayload>> {
+        let client = self.pool.get().await?;
+
+        let statement = "
+            SELECT
+                payload
+            FROM
+                public.event_log
+            WHERE
+                event_type = 'INVITE_CHECK_CREATE'
+                AND (payload->>'guild_id')::INT8 = $1
+            ORDER BY
+                created_at DESC
+            LIMIT
+                $2
+            OFFSET
+                $3;
+        ";
+
+        let params: &[&(dyn ToSql + Sync)] = &[&(guild_id.get() as i64), &limit, &offset];
+        let rows = client.query(statement, params).await?;
+        let mut invite_checks = Vec::with_capacity(rows.len());
+
+        for row in rows {
+            invite_checks.push(serde_json::from_value(row.get::<_, Value>("payload"))?);
+        }
+
+        Ok(invite_checks)
+    }
+
+    async fn insert_event(
+        client: &impl GenericClient,
+        event: Event,
+        payload: impl Serialize,
+    ) -> Result<i64> {
         let statement = "
             INSERT INTO
                 public.event_log (event_type, payload)
             VALUES
-                ($1, $2);
+                ($1, $2)
+            RETURNING
+                event_id;
         ";
 
         let params: &[&(dyn ToSql + Sync)] = &[&event, &serde_json::to_value(payload)?];
+        let row = client.query_one(statement, params).await?;
 
-        client.execute(statement, params).await?;
-
-        Ok(())
+        Ok(row.get::<_, i64>("event_id"))
     }
 
     pub async fn insert_guild_create_event(
         &self,
         payload: GuildCreatePayload,
     ) -> Result<()> {
-        self.insert_event(Event::GuildCreate, payload).await?;
+        let client = self.pool.get().await?;
+
+        Self::insert_event(&client, Event::GuildCreate, payload).await?;
 
         Ok(())
     }
@@ -41,17 +109,29 @@ impl Database {
         &self,
         payload: GuildDeletePayload,
     ) -> Result<()> {
-        self.insert_event(Event::GuildDelete, payload).await?;
+        let client = self.pool.get().await?;
+
+        Self::insert_event(&client, Event::GuildDelete, payload).await?;
 
         Ok(())
     }
 
+    // The channels are written in the same transaction, so that a check is never
+    // left without them
     pub async fn insert_invite_check_create_event(
         &self,
         payload: InviteCheckCreatePayload,
-    ) -> Result<()> {
-        self.insert_event(Event::InviteCheckCreate, payload).await?;
+        channels: &[InviteCheckChannel],
+    ) -> Result<i64> {
+        let mut client = self.pool.get().await?;
+        let transaction = client.transaction().await?;
 
-        Ok(())
+        let check_id = Self::insert_event(&transaction, Event::InviteCheckCreate, payload).await?;
+
+        Self::insert_invite_check_channels(&transaction, check_id, channels).await?;
+
+        transaction.commit().await?;
+
+        Ok(check_id)
     }
 }
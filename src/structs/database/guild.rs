@@ -1,17 +1,74 @@
 use std::collections::HashSet;
 
+use fancy_regex::{escape, Regex};
+use serde_json::Value;
 use tokio_postgres::{types::ToSql, Row};
-use twilight_model::id::{
-    marker::{ChannelMarker, GuildMarker},
-    Id,
+use twilight_model::{
+    channel::ChannelType,
+    id::{
+        marker::{ChannelMarker, GuildMarker, UserMarker},
+        Id,
+    },
 };
 
-use crate::types::{
-    database::{Database, Guild},
-    Result,
+use crate::{
+    types::{
+        cache,
+        database::{Database, Guild, IgnoreRule, InviteCountingMode},
+        Result,
+    },
+    utility::constants::GUILD_MEDIA_CHANNEL_TYPE,
 };
 
 impl Database {
+    pub async fn get_check_schedules(&self) -> Result<Vec<(Id<GuildMarker>, String)>> {
+        let client = self.pool.get().await?;
+        let statement = "
+            SELECT
+                guild_id,
+                check_schedule
+            FROM
+                public.guild
+            WHERE
+                check_schedule IS NOT NULL;
+        ";
+        let params: &[&(dyn ToSql + Sync)] = &[];
+        let check_schedules = client
+            .query(statement, params)
+            .await?
+            .into_iter()
+            .map(|row| {
+                (
+                    Id::new(row.get::<_, i64>("guild_id") as u64),
+                    row.get::<_, String>("check_schedule"),
+                )
+            })
+            .collect();
+
+        Ok(check_schedules)
+    }
+
+    pub async fn get_expiry_warning_guild_ids(&self) -> Result<Vec<Id<GuildMarker>>> {
+        let client = self.pool.get().await?;
+        let statement = "
+            SELECT
+                guild_id
+            FROM
+                public.guild
+            WHERE
+                expiry_warning_days IS NOT NULL;
+        ";
+        let params: &[&(dyn ToSql + Sync)] = &[];
+        let guild_ids = client
+            .query(statement, params)
+            .await?
+            .into_iter()
+            .map(|row| Id::new(row.get::<_, i64>("guild_id") as u64))
+            .collect();
+
+        Ok(guild_ids)
+    }
+
     pub async fn get_guild(
         &self,
         guild_id: Id<GuildMarker>,
@@ -33,6 +90,73 @@ impl Database {
             .map_or(None, |row| Some(row.into()))
     }
 
+    pub async fn insert_alerts_channel(
+        &self,
+        guild_id: Id<GuildMarker>,
+        channel_id: Id<ChannelMarker>,
+    ) -> Result<()> {
+        let client = self.pool.get().await?;
+        let statement = "
+            UPDATE
+                public.guild
+            SET
+                alerts_channel_id = $2
+            WHERE
+                guild_id = $1;
+        ";
+        let params: &[&(dyn ToSql + Sync)] =
+            &[&(guild_id.get() as i64), &(channel_id.get() as i64)];
+
+        client.execute(statement, params).await?;
+
+        Ok(())
+    }
+
+    pub async fn insert_allow_duplicate_guilds(
+        &self,
+        guild_id: Id<GuildMarker>,
+        allow_duplicate_guilds: bool,
+    ) -> Result<()> {
+        let client = self.pool.get().await?;
+        let statement = "
+            UPDATE
+                public.guild
+            SET
+                allow_duplicate_guilds = $2
+            WHERE
+                guild_id = $1;
+        ";
+        let params: &[&(dyn ToSql + Sync)] = &[&(guild_id.get() as i64), &allow_duplicate_guilds];
+
+        client.execute(statement, params).await?;
+
+        Ok(())
+    }
+
+    pub async fn insert_blocked_guild(
+        &self,
+        guild_id: Id<GuildMarker>,
+        blocked_guild_id: Id<GuildMarker>,
+    ) -> Result<()> {
+        let client = self.pool.get().await?;
+        let statement = "
+            UPDATE
+                public.guild
+            SET
+                blocked_guild_ids = ARRAY(
+                    SELECT DISTINCT UNNEST(ARRAY_APPEND(blocked_guild_ids, $2))
+                )
+            WHERE
+                guild_id = $1;
+        ";
+        let params: &[&(dyn ToSql + Sync)] =
+            &[&(guild_id.get() as i64), &(blocked_guild_id.get() as i64)];
+
+        client.execute(statement, params).await?;
+
+        Ok(())
+    }
+
     pub async fn insert_category_channel(
         &self,
         guild_id: Id<GuildMarker>,
@@ -67,6 +191,27 @@ impl Database {
         Ok(updated_category_channel_ids)
     }
 
+    pub async fn insert_check_schedule(
+        &self,
+        guild_id: Id<GuildMarker>,
+        check_schedule: &str,
+    ) -> Result<()> {
+        let client = self.pool.get().await?;
+        let statement = "
+            UPDATE
+                public.guild
+            SET
+                check_schedule = $2
+            WHERE
+                guild_id = $1;
+        ";
+        let params: &[&(dyn ToSql + Sync)] = &[&(guild_id.get() as i64), &check_schedule];
+
+        client.execute(statement, params).await?;
+
+        Ok(())
+    }
+
     pub async fn insert_embed_color(
         &self,
         guild_id: Id<GuildMarker>,
@@ -88,6 +233,27 @@ impl Database {
         Ok(())
     }
 
+    pub async fn insert_expiry_warning_days(
+        &self,
+        guild_id: Id<GuildMarker>,
+        expiry_warning_days: Option<i32>,
+    ) -> Result<()> {
+        let client = self.pool.get().await?;
+        let statement = "
+            UPDATE
+                public.guild
+            SET
+                expiry_warning_days = $2
+            WHERE
+                guild_id = $1;
+        ";
+        let params: &[&(dyn ToSql + Sync)] = &[&(guild_id.get() as i64), &expiry_warning_days];
+
+        client.execute(statement, params).await?;
+
+        Ok(())
+    }
+
     pub async fn insert_guild(
         &self,
         guild_id: Id<GuildMarker>,
@@ -108,6 +274,31 @@ impl Database {
         Ok(())
     }
 
+    pub async fn insert_ignore_rule(
+        &self,
+        guild_id: Id<GuildMarker>,
+        ignore_rule: &IgnoreRule,
+    ) -> Result<()> {
+        let client = self.pool.get().await?;
+        let statement = "
+            UPDATE
+                public.guild
+            SET
+                ignore_rules = CASE
+                    WHEN ignore_rules @> $2 THEN ignore_rules
+                    ELSE ignore_rules || $2
+                END
+            WHERE
+                guild_id = $1;
+        ";
+        let params: &[&(dyn ToSql + Sync)] =
+            &[&(guild_id.get() as i64), &serde_json::to_value([ignore_rule])?];
+
+        client.execute(statement, params).await?;
+
+        Ok(())
+    }
+
     pub async fn insert_ignored_channel(
         &self,
         guild_id: Id<GuildMarker>,
@@ -132,6 +323,91 @@ impl Database {
         Ok(())
     }
 
+    pub async fn insert_invalid_invite_alerts(
+        &self,
+        guild_id: Id<GuildMarker>,
+        invalid_invite_alerts: bool,
+    ) -> Result<()> {
+        let client = self.pool.get().await?;
+        let statement = "
+            UPDATE
+                public.guild
+            SET
+                invalid_invite_alerts = $2
+            WHERE
+                guild_id = $1;
+        ";
+        let params: &[&(dyn ToSql + Sync)] = &[&(guild_id.get() as i64), &invalid_invite_alerts];
+
+        client.execute(statement, params).await?;
+
+        Ok(())
+    }
+
+    pub async fn insert_invite_counting_mode(
+        &self,
+        guild_id: Id<GuildMarker>,
+        invite_counting_mode: InviteCountingMode,
+    ) -> Result<()> {
+        let client = self.pool.get().await?;
+        let statement = "
+            UPDATE
+                public.guild
+            SET
+                invite_counting_mode = $2
+            WHERE
+                guild_id = $1;
+        ";
+        let params: &[&(dyn ToSql + Sync)] = &[&(guild_id.get() as i64), &invite_counting_mode];
+
+        client.execute(statement, params).await?;
+
+        Ok(())
+    }
+
+    pub async fn insert_minimum_member_count(
+        &self,
+        guild_id: Id<GuildMarker>,
+        minimum_member_count: Option<i32>,
+    ) -> Result<()> {
+        let client = self.pool.get().await?;
+        let statement = "
+            UPDATE
+                public.guild
+            SET
+                minimum_member_count = $2
+            WHERE
+                guild_id = $1;
+        ";
+        let params: &[&(dyn ToSql + Sync)] = &[&(guild_id.get() as i64), &minimum_member_count];
+
+        client.execute(statement, params).await?;
+
+        Ok(())
+    }
+
+    pub async fn insert_require_permanent_invites(
+        &self,
+        guild_id: Id<GuildMarker>,
+        require_permanent_invites: bool,
+    ) -> Result<()> {
+        let client = self.pool.get().await?;
+        let statement = "
+            UPDATE
+                public.guild
+            SET
+                require_permanent_invites = $2
+            WHERE
+                guild_id = $1;
+        ";
+        let params: &[&(dyn ToSql + Sync)] =
+            &[&(guild_id.get() as i64), &require_permanent_invites];
+
+        client.execute(statement, params).await?;
+
+        Ok(())
+    }
+
     pub async fn insert_results_channel(
         &self,
         guild_id: Id<GuildMarker>,
@@ -154,6 +430,123 @@ impl Database {
         Ok(())
     }
 
+    pub async fn insert_scan_author(
+        &self,
+        guild_id: Id<GuildMarker>,
+        author_id: Id<UserMarker>,
+    ) -> Result<()> {
+        let client = self.pool.get().await?;
+        let statement = "
+            UPDATE
+                public.guild
+            SET
+                scan_author_ids = ARRAY(
+                    SELECT DISTINCT UNNEST(ARRAY_APPEND(scan_author_ids, $2))
+                )
+            WHERE
+                guild_id = $1;
+        ";
+        let params: &[&(dyn ToSql + Sync)] = &[&(guild_id.get() as i64), &(author_id.get() as i64)];
+
+        client.execute(statement, params).await?;
+
+        Ok(())
+    }
+
+    pub async fn insert_scan_depth(
+        &self,
+        guild_id: Id<GuildMarker>,
+        scan_depth: i32,
+    ) -> Result<()> {
+        let client = self.pool.get().await?;
+        let statement = "
+            UPDATE
+                public.guild
+            SET
+                scan_depth = $2
+            WHERE
+                guild_id = $1;
+        ";
+        let params: &[&(dyn ToSql + Sync)] = &[&(guild_id.get() as i64), &scan_depth];
+
+        client.execute(statement, params).await?;
+
+        Ok(())
+    }
+
+    pub async fn insert_tracked_channel(
+        &self,
+        guild_id: Id<GuildMarker>,
+        channel_id: Id<ChannelMarker>,
+    ) -> Result<HashSet<Id<ChannelMarker>>> {
+        let client = self.pool.get().await?;
+        let statement = "
+            UPDATE
+                public.guild
+            SET
+                tracked_channel_ids = ARRAY(
+                    SELECT DISTINCT UNNEST(ARRAY_APPEND(tracked_channel_ids, $2))
+                )
+            WHERE
+                guild_id = $1
+            RETURNING
+                tracked_channel_ids;
+        ";
+        let params: &[&(dyn ToSql + Sync)] =
+            &[&(guild_id.get() as i64), &(channel_id.get() as i64)];
+        let updated_tracked_channel_ids = client
+            .query_one(statement, params)
+            .await?
+            .get::<_, Vec<i64>>("tracked_channel_ids")
+            .into_iter()
+            .map(|id| Id::new(id as u64))
+            .collect();
+
+        Ok(updated_tracked_channel_ids)
+    }
+
+    pub async fn remove_alerts_channel(
+        &self,
+        guild_id: Id<GuildMarker>,
+    ) -> Result<()> {
+        let client = self.pool.get().await?;
+        let statement = "
+            UPDATE
+                public.guild
+            SET
+                alerts_channel_id = NULL
+            WHERE
+                guild_id = $1;
+        ";
+        let params: &[&(dyn ToSql + Sync)] = &[&(guild_id.get() as i64)];
+
+        client.execute(statement, params).await?;
+
+        Ok(())
+    }
+
+    pub async fn remove_blocked_guild(
+        &self,
+        guild_id: Id<GuildMarker>,
+        blocked_guild_id: Id<GuildMarker>,
+    ) -> Result<()> {
+        let client = self.pool.get().await?;
+        let statement = "
+            UPDATE
+                public.guild
+            SET
+                blocked_guild_ids = ARRAY_REMOVE(blocked_guild_ids, $2)
+            WHERE
+                guild_id = $1;
+        ";
+        let params: &[&(dyn ToSql + Sync)] =
+            &[&(guild_id.get() as i64), &(blocked_guild_id.get() as i64)];
+
+        client.execute(statement, params).await?;
+
+        Ok(())
+    }
+
     pub async fn remove_channel(
         &self,
         guild_id: Id<GuildMarker>,
@@ -170,6 +563,7 @@ impl Database {
                 ignored_channel_ids = ARRAY(
                         SELECT DISTINCT UNNEST(ARRAY_REMOVE(ignored_channel_ids, $2))
                 ),
+                tracked_channel_ids = ARRAY_REMOVE(tracked_channel_ids, $2),
                 results_channel_id = CASE
                     WHEN results_channel_id = $2 THEN NULL
                     ELSE results_channel_id
@@ -195,6 +589,26 @@ impl Database {
         Ok(updated_category_channel_ids)
     }
 
+    pub async fn remove_check_schedule(
+        &self,
+        guild_id: Id<GuildMarker>,
+    ) -> Result<()> {
+        let client = self.pool.get().await?;
+        let statement = "
+            UPDATE
+                public.guild
+            SET
+                check_schedule = NULL
+            WHERE
+                guild_id = $1;
+        ";
+        let params: &[&(dyn ToSql + Sync)] = &[&(guild_id.get() as i64)];
+
+        client.execute(statement, params).await?;
+
+        Ok(())
+    }
+
     pub async fn remove_guild(
         &self,
         guild_id: Id<GuildMarker>,
@@ -212,6 +626,85 @@ impl Database {
 
         Ok(())
     }
+
+    pub async fn remove_ignore_rule(
+        &self,
+        guild_id: Id<GuildMarker>,
+        ignore_rule: &IgnoreRule,
+    ) -> Result<()> {
+        let client = self.pool.get().await?;
+        let statement = "
+            UPDATE
+                public.guild
+            SET
+                ignore_rules = (
+                    SELECT
+                        COALESCE(JSONB_AGG(ignore_rule), '[]')
+                    FROM
+                        JSONB_ARRAY_ELEMENTS(ignore_rules) ignore_rule
+                    WHERE
+                        ignore_rule <> $2
+                )
+            WHERE
+                guild_id = $1;
+        ";
+        let params: &[&(dyn ToSql + Sync)] =
+            &[&(guild_id.get() as i64), &serde_json::to_value(ignore_rule)?];
+
+        client.execute(statement, params).await?;
+
+        Ok(())
+    }
+
+    pub async fn remove_scan_author(
+        &self,
+        guild_id: Id<GuildMarker>,
+        author_id: Id<UserMarker>,
+    ) -> Result<()> {
+        let client = self.pool.get().await?;
+        let statement = "
+            UPDATE
+                public.guild
+            SET
+                scan_author_ids = ARRAY_REMOVE(scan_author_ids, $2)
+            WHERE
+                guild_id = $1;
+        ";
+        let params: &[&(dyn ToSql + Sync)] = &[&(guild_id.get() as i64), &(author_id.get() as i64)];
+
+        client.execute(statement, params).await?;
+
+        Ok(())
+    }
+
+    pub async fn remove_tracked_channel(
+        &self,
+        guild_id: Id<GuildMarker>,
+        channel_id: Id<ChannelMarker>,
+    ) -> Result<HashSet<Id<ChannelMarker>>> {
+        let client = self.pool.get().await?;
+        let statement = "
+            UPDATE
+                public.guild
+            SET
+                tracked_channel_ids = ARRAY_REMOVE(tracked_channel_ids, $2)
+            WHERE
+                guild_id = $1
+            RETURNING
+                tracked_channel_ids;
+        ";
+        let params: &[&(dyn ToSql + Sync)] =
+            &[&(guild_id.get() as i64), &(channel_id.get() as i64)];
+        let updated_tracked_channel_ids = client
+            .query_one(statement, params)
+            .await?
+            .get::<_, Vec<i64>>("tracked_channel_ids")
+            .into_iter()
+            .map(|id| Id::new(id as u64))
+            .collect();
+
+        Ok(updated_tracked_channel_ids)
+    }
 }
 
 impl From<Row> for Guild {
@@ -232,6 +725,135 @@ impl From<Row> for Guild {
             results_channel_id: row
                 .try_get::<_, i64>("results_channel_id")
                 .map_or(None, |channel_id| Some(Id::new(channel_id as u64))),
+            check_schedule: row.get::<_, Option<String>>("check_schedule"),
+            minimum_member_count: row.get::<_, Option<i32>>("minimum_member_count"),
+            allow_duplicate_guilds: row.get::<_, bool>("allow_duplicate_guilds"),
+            blocked_guild_ids: row
+                .get::<_, Vec<i64>>("blocked_guild_ids")
+                .into_iter()
+                .map(|id| Id::new(id as u64))
+                .collect(),
+            require_permanent_invites: row.get::<_, bool>("require_permanent_invites"),
+            alerts_channel_id: row
+                .try_get::<_, i64>("alerts_channel_id")
+                .map_or(None, |channel_id| Some(Id::new(channel_id as u64))),
+            expiry_warning_days: row.get::<_, Option<i32>>("expiry_warning_days"),
+            invalid_invite_alerts: row.get::<_, bool>("invalid_invite_alerts"),
+            scan_depth: row.get::<_, i32>("scan_depth"),
+            invite_counting_mode: row.get::<_, InviteCountingMode>("invite_counting_mode"),
+            ignore_rules: serde_json::from_value(row.get::<_, Value>("ignore_rules"))
+                .unwrap_or_default(),
+            scan_author_ids: row
+                .get::<_, Vec<i64>>("scan_author_ids")
+                .into_iter()
+                .map(|id| Id::new(id as u64))
+                .collect(),
+            tracked_channel_ids: row
+                .get::<_, Vec<i64>>("tracked_channel_ids")
+                .into_iter()
+                .map(|id| Id::new(id as u64))
+                .collect(),
         }
     }
 }
+
+impl Guild {
+    // Alerts go to the results channel unless a separate alerts channel is set
+    pub fn get_alerts_channel_id(&self) -> Option<Id<ChannelMarker>> {
+        self.alerts_channel_id.or(self.results_channel_id)
+    }
+
+    // Messages from anyone are scanned until specific authors are added
+    pub fn is_scanned_author(
+        &self,
+        author_id: Id<UserMarker>,
+    ) -> bool {
+        self.scan_author_ids.is_empty() || self.scan_author_ids.contains(&author_id)
+    }
+}
+
+impl IgnoreRule {
+    pub fn description(&self) -> String {
+        match self {
+            Self::ChannelType {
+                channel_type,
+            } => {
+                match ChannelType::from(*channel_type) {
+                    ChannelType::GuildAnnouncement => "Announcement channels".to_owned(),
+                    ChannelType::GuildForum => "Forum channels".to_owned(),
+                    GUILD_MEDIA_CHANNEL_TYPE => "Media channels".to_owned(),
+                    ChannelType::GuildText => "Text channels".to_owned(),
+                    kind => format!("Channels of type {}", u8::from(kind)),
+                }
+            }
+            Self::NameGlob {
+                pattern,
+            } => format!("Names matching `{pattern}`"),
+            Self::NameRegex {
+                pattern,
+            } => format!("Names matching the regex `{pattern}`"),
+            Self::TopicKeyword {
+                keyword,
+            } => format!("Topics containing \"{keyword}\""),
+        }
+    }
+
+    pub fn matches(
+        &self,
+        channel: &cache::Channel,
+    ) -> bool {
+        match self {
+            Self::ChannelType {
+                channel_type,
+            } => u8::from(channel.kind) == *channel_type,
+            Self::NameGlob {
+                pattern,
+            } => {
+                get_glob_regex(pattern)
+                    .is_some_and(|regex| regex.is_match(&channel.name).unwrap_or(false))
+            }
+            Self::NameRegex {
+                pattern,
+            } => {
+                Regex::new(pattern)
+                    .is_ok_and(|regex| regex.is_match(&channel.name).unwrap_or(false))
+            }
+            Self::TopicKeyword {
+                keyword,
+            } => {
+                channel
+                    .topic
+                    .as_ref()
+                    .is_some_and(|topic| topic.to_lowercase().contains(&keyword.to_lowercase()))
+            }
+        }
+    }
+}
+
+impl InviteCountingMode {
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::All => "all messages",
+            Self::First => "first message",
+            Self::Latest => "latest message",
+            Self::Pinned => "pinned messages",
+        }
+    }
+}
+
+// Globs only support `*` and `?`, and match the whole name regardless of case
+fn get_glob_regex(pattern: &str) -> Option<Regex> {
+    let mut regex = "(?i)^".to_owned();
+
+    for character in pattern.chars() {
+        match character {
+            '*' => regex.push_str(".*"),
+            '?' => regex.push('.'),
+            _ => regex.push_str(&escape(&character.to_string())),
+        }
+    }
+
+    regex.push('$');
+
+    Regex::new(&regex).ok()
+}
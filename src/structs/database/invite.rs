@@ -1,59 +1,67 @@
 use std::collections::HashMap;
 
-use time::OffsetDateTime;
+use serde_json::Value;
+use time::{Duration, OffsetDateTime};
 use tokio_postgres::types::ToSql;
 use twilight_model::id::{
     marker::{ChannelMarker, GuildMarker},
     Id,
 };
 
-use crate::types::{database::Database, Result};
+use crate::types::{
+    database::{Database, InviteLocation, InviteStatus, InviteTarget},
+    resolver::InviteResolution,
+    Result,
+};
 
 impl Database {
     pub async fn get_guild_invite_counts(
         &self,
         guild_id: Id<GuildMarker>,
-    ) -> Result<HashMap<Id<ChannelMarker>, (u16, u16, u16)>> {
+    ) -> Result<HashMap<Id<ChannelMarker>, (u16, u16, u16, u16)>> {
         let client = self.pool.get().await?;
 
         let statement = "
             WITH guild_invite AS (
                 SELECT
-                    public.message.channel_id,
+                    public.counted_message.channel_id,
                     _.code,
                     COALESCE(public.invite.is_valid, FALSE) AS is_valid,
+                    COALESCE(public.invite.is_temporary, FALSE) AS is_temporary,
                     CASE
                         WHEN public.invite.updated_at IS NULL THEN FALSE
                         ELSE TRUE
                     END AS is_updated
                 FROM
-                    public.message,
-                    UNNEST(public.message.invite_codes) _(code)
+                    public.counted_message,
+                    UNNEST(public.counted_message.invite_codes) _(code)
                     LEFT JOIN public.invite ON public.invite.code = _.code
                 WHERE
                     guild_id = $1
             )
             SELECT
                 channel_id,
-                COUNT(*) FILTER (WHERE guild_invite.is_valid AND guild_invite.is_updated)::INT2 AS valid_invites,
+                COUNT(*) FILTER (WHERE guild_invite.is_valid AND guild_invite.is_updated AND NOT guild_invite.is_temporary)::INT2 AS permanent_invites,
                 COUNT(*) FILTER (WHERE NOT guild_invite.is_valid AND guild_invite.is_updated)::INT2 AS invalid_invites,
-                COUNT(*) FILTER (WHERE NOT guild_invite.is_updated)::INT2 AS unknown_invites
+                COUNT(*) FILTER (WHERE NOT guild_invite.is_updated)::INT2 AS unknown_invites,
+                COUNT(*) FILTER (WHERE guild_invite.is_valid AND guild_invite.is_updated AND guild_invite.is_temporary)::INT2 AS temporary_invites
             FROM
                 guild_invite
             GROUP BY
                 guild_invite.channel_id;
         ";
         let params: &[&(dyn ToSql + Sync)] = &[&(guild_id.get() as i64)];
-        let mut invite_check: HashMap<Id<ChannelMarker>, (u16, u16, u16)> = HashMap::new();
+        let mut invite_check: HashMap<Id<ChannelMarker>, (u16, u16, u16, u16)> = HashMap::new();
 
         if let Ok(rows) = client.query(statement, params).await {
             for row in rows {
                 invite_check.insert(
                     Id::new(row.get::<_, i64>("channel_id") as u64),
                     (
-                        row.get::<_, i16>("valid_invites") as u16,
+                        row.get::<_, i16>("permanent_invites") as u16,
                         row.get::<_, i16>("invalid_invites") as u16,
                         row.get::<_, i16>("unknown_invites") as u16,
+                        row.get::<_, i16>("temporary_invites") as u16,
                     ),
                 );
             }
@@ -62,9 +70,187 @@ impl Database {
         Ok(invite_check)
     }
 
-    pub async fn get_unchecked_invites(&self) -> Result<Vec<String>> {
+    pub async fn get_guild_invite_status_counts(
+        &self,
+        guild_id: Id<GuildMarker>,
+    ) -> Result<HashMap<Id<ChannelMarker>, Vec<(Option<InviteStatus>, u16)>>> {
+        let client = self.pool.get().await?;
+
+        let statement = "
+            SELECT
+                public.counted_message.channel_id,
+                public.invite.status,
+                COUNT(*)::INT2 AS invites
+            FROM
+                public.counted_message,
+                UNNEST(public.counted_message.invite_codes) _(code)
+                LEFT JOIN public.invite ON public.invite.code = _.code
+            WHERE
+                guild_id = $1
+                AND NOT COALESCE(public.invite.is_valid AND public.invite.updated_at IS NOT NULL, FALSE)
+            GROUP BY
+                public.counted_message.channel_id,
+                public.invite.status
+            ORDER BY
+                invites DESC;
+        ";
+        let params: &[&(dyn ToSql + Sync)] = &[&(guild_id.get() as i64)];
+        let mut status_counts: HashMap<Id<ChannelMarker>, Vec<(Option<InviteStatus>, u16)>> =
+            HashMap::new();
+
+        for row in client.query(statement, params).await? {
+            status_counts
+                .entry(Id::new(row.get::<_, i64>("channel_id") as u64))
+                .or_default()
+                .push((
+                    row.get::<_, Option<InviteStatus>>("status"),
+                    row.get::<_, i16>("invites") as u16,
+                ));
+        }
+
+        Ok(status_counts)
+    }
+
+    pub async fn get_guild_invalid_invite_locations(
+        &self,
+        guild_id: Id<GuildMarker>,
+    ) -> Result<HashMap<Id<ChannelMarker>, Vec<(String, Option<InviteLocation>)>>> {
         let client = self.pool.get().await?;
 
+        let statement = "
+            SELECT
+                public.counted_message.channel_id,
+                _.code,
+                public.counted_message.invite_locations->_.code AS location
+            FROM
+                public.counted_message,
+                UNNEST(public.counted_message.invite_codes) _(code)
+                JOIN public.invite ON public.invite.code = _.code
+            WHERE
+                guild_id = $1
+                AND NOT public.invite.is_valid
+                AND public.invite.updated_at IS NOT NULL
+            ORDER BY
+                public.counted_message.channel_id,
+                _.code;
+        ";
+        let params: &[&(dyn ToSql + Sync)] = &[&(guild_id.get() as i64)];
+        let mut invite_locations: HashMap<
+            Id<ChannelMarker>,
+            Vec<(String, Option<InviteLocation>)>,
+        > = HashMap::new();
+
+        for row in client.query(statement, params).await? {
+            // Messages stored before locations were recorded have none
+            let location = row
+                .get::<_, Option<Value>>("location")
+                .and_then(|location| serde_json::from_value(location).ok());
+
+            invite_locations
+                .entry(Id::new(row.get::<_, i64>("channel_id") as u64))
+                .or_default()
+                .push((row.get::<_, String>("code"), location));
+        }
+
+        Ok(invite_locations)
+    }
+
+    pub async fn get_guild_invite_targets(
+        &self,
+        guild_id: Id<GuildMarker>,
+    ) -> Result<HashMap<Id<ChannelMarker>, Vec<InviteTarget>>> {
+        let client = self.pool.get().await?;
+
+        let statement = "
+            SELECT DISTINCT ON (public.counted_message.channel_id, public.invite.target_guild_id)
+                public.counted_message.channel_id,
+                public.invite.target_guild_id,
+                public.invite.target_guild_name,
+                public.invite.member_count,
+                public.invite.online_count
+            FROM
+                public.counted_message,
+                UNNEST(public.counted_message.invite_codes) _(code)
+                JOIN public.invite ON public.invite.code = _.code
+            WHERE
+                guild_id = $1
+                AND public.invite.is_valid
+                AND public.invite.target_guild_id IS NOT NULL
+            ORDER BY
+                public.counted_message.channel_id,
+                public.invite.target_guild_id,
+                public.invite.updated_at DESC;
+        ";
+        let params: &[&(dyn ToSql + Sync)] = &[&(guild_id.get() as i64)];
+        let mut invite_targets: HashMap<Id<ChannelMarker>, Vec<InviteTarget>> = HashMap::new();
+
+        for row in client.query(statement, params).await? {
+            invite_targets
+                .entry(Id::new(row.get::<_, i64>("channel_id") as u64))
+                .or_default()
+                .push(InviteTarget {
+                    guild_id: Id::new(row.get::<_, i64>("target_guild_id") as u64),
+                    name: row.get::<_, String>("target_guild_name"),
+                    member_count: row.get::<_, Option<i32>>("member_count"),
+                    online_count: row.get::<_, Option<i32>>("online_count"),
+                });
+        }
+
+        Ok(invite_targets)
+    }
+
+    pub async fn get_guild_expiring_invites(
+        &self,
+        guild_id: Id<GuildMarker>,
+        window: Duration,
+    ) -> Result<HashMap<Id<ChannelMarker>, Vec<(String, OffsetDateTime)>>> {
+        let client = self.pool.get().await?;
+
+        let statement = "
+            SELECT DISTINCT
+                public.counted_message.channel_id,
+                public.invite.code,
+                public.invite.expires_at
+            FROM
+                public.counted_message,
+                UNNEST(public.counted_message.invite_codes) _(code)
+                JOIN public.invite ON public.invite.code = _.code
+            WHERE
+                guild_id = $1
+                AND public.invite.is_valid
+                AND public.invite.expires_at > CURRENT_TIMESTAMP
+                AND public.invite.expires_at <= CURRENT_TIMESTAMP + $2::INT8 * INTERVAL '1 second'
+            ORDER BY
+                public.invite.expires_at;
+        ";
+        let params: &[&(dyn ToSql + Sync)] = &[&(guild_id.get() as i64), &window.whole_seconds()];
+        let mut expiring_invites: HashMap<Id<ChannelMarker>, Vec<(String, OffsetDateTime)>> =
+            HashMap::new();
+
+        for row in client.query(statement, params).await? {
+            expiring_invites
+                .entry(Id::new(row.get::<_, i64>("channel_id") as u64))
+                .or_default()
+                .push((
+                    row.get::<_, String>("code"),
+                    row.get::<_, OffsetDateTime>("expires_at"),
+                ));
+        }
+
+        Ok(expiring_invites)
+    }
+
+    pub async fn get_invites_to_check(
+        &self,
+        revalidation_age: Duration,
+        expiry_margin: Duration,
+        limit: i64,
+    ) -> Result<Vec<String>> {
+        let client = self.pool.get().await?;
+
+        // Besides unchecked invites, recheck invites that have not been checked in a
+        // while and valid invites that are about to expire or have expired
+        // since they were last checked
         let statement = "
             SELECT
                 code
@@ -72,12 +258,25 @@ impl Database {
                 public.invite
             WHERE
                 updated_at IS NULL
+                OR updated_at <= CURRENT_TIMESTAMP - $1::INT8 * INTERVAL '1 second'
+                OR (
+                    is_valid
+                    AND expires_at <= CURRENT_TIMESTAMP + $2::INT8 * INTERVAL '1 second'
+                    AND updated_at < expires_at - $2::INT8 * INTERVAL '1 second'
+                )
+                OR (
+                    is_valid
+                    AND expires_at <= CURRENT_TIMESTAMP
+                    AND updated_at < expires_at
+                )
             ORDER BY
+                updated_at NULLS FIRST,
                 created_at
             LIMIT
-                20;
+                $3;
         ";
-        let params: &[&(dyn ToSql + Sync)] = &[];
+        let params: &[&(dyn ToSql + Sync)] =
+            &[&revalidation_age.whole_seconds(), &expiry_margin.whole_seconds(), &limit];
         let rows = client.query(statement, params).await?;
         let codes = rows
             .into_iter()
@@ -102,10 +301,17 @@ impl Database {
             DO UPDATE
             SET
                 is_permalink = NULL,
+                is_temporary = NULL,
                 is_valid = NULL,
                 expires_at = NULL,
                 created_at = CURRENT_TIMESTAMP,
-                updated_at = NULL;
+                updated_at = NULL,
+                status = NULL,
+                error_code = NULL,
+                target_guild_id = NULL,
+                target_guild_name = NULL,
+                member_count = NULL,
+                online_count = NULL;
         ";
 
         let params: &[&(dyn ToSql + Sync)] = &[&code];
@@ -118,35 +324,121 @@ impl Database {
     pub async fn insert_checked_invite(
         &self,
         code: &str,
-        is_permalink: bool,
-        is_valid: bool,
-        expires_at: Option<OffsetDateTime>,
+        resolution: &InviteResolution,
         updated_at: OffsetDateTime,
-    ) -> Result<()> {
+    ) -> Result<bool> {
         let client = self.pool.get().await?;
 
+        // The CTE sees the invite as it was before this statement, so whether it was
+        // already alerted about can be returned alongside the upsert. Unlike the
+        // validity, alerted_at survives the invite being posted again
         let statement = "
+            WITH previous_invite AS (
+                SELECT
+                    alerted_at
+                FROM
+                    public.invite
+                WHERE
+                    code = $1
+            )
             INSERT INTO
                 public.invite (
                     code,
                     is_permalink,
+                    is_temporary,
                     is_valid,
                     expires_at,
-                    updated_at
+                    updated_at,
+                    status,
+                    error_code,
+                    target_guild_id,
+                    target_guild_name,
+                    member_count,
+                    online_count,
+                    alerted_at
                 )
             VALUES
-                ($1, $2, $3, $4, $5)
+                (
+                    $1,
+                    $2,
+                    $3,
+                    $4,
+                    $5,
+                    $6,
+                    $7,
+                    $8,
+                    $9,
+                    $10,
+                    $11,
+                    $12,
+                    CASE
+                        WHEN $4::BOOLEAN THEN NULL
+                        ELSE $6::TIMESTAMPTZ
+                    END
+                )
             ON CONFLICT (code)
             DO UPDATE
             SET
                 is_permalink = EXCLUDED.is_permalink,
+                is_temporary = EXCLUDED.is_temporary,
                 is_valid = EXCLUDED.is_valid,
                 expires_at = EXCLUDED.expires_at,
-                updated_at = EXCLUDED.updated_at;
+                updated_at = EXCLUDED.updated_at,
+                status = EXCLUDED.status,
+                error_code = EXCLUDED.error_code,
+                target_guild_id = EXCLUDED.target_guild_id,
+                target_guild_name = EXCLUDED.target_guild_name,
+                member_count = EXCLUDED.member_count,
+                online_count = EXCLUDED.online_count,
+                alerted_at = CASE
+                    WHEN EXCLUDED.is_valid THEN NULL
+                    ELSE COALESCE(public.invite.alerted_at, EXCLUDED.alerted_at)
+                END
+            RETURNING
+                NOT is_valid AND (SELECT alerted_at FROM previous_invite) IS NULL AS is_alert_due;
         ";
 
-        let params: &[&(dyn ToSql + Sync)] =
-            &[&code, &is_permalink, &is_valid, &expires_at, &updated_at];
+        let target = resolution.target.as_ref();
+        let params: &[&(dyn ToSql + Sync)] = &[
+            &code,
+            &resolution.is_permalink,
+            &resolution.is_temporary,
+            &(resolution.status == InviteStatus::Valid),
+            &resolution.expires_at,
+            &updated_at,
+            &resolution.status,
+            &resolution.error_code,
+            &target.map(|target| target.guild_id.get() as i64),
+            &target.map(|target| target.name.as_str()),
+            &target.and_then(|target| target.member_count),
+            &target.and_then(|target| target.online_count),
+        ];
+        let row = client.query_one(statement, params).await?;
+
+        Ok(row.get::<_, bool>("is_alert_due"))
+    }
+
+    pub async fn insert_failed_invite_attempt(
+        &self,
+        code: &str,
+        resolution: &InviteResolution,
+    ) -> Result<()> {
+        let client = self.pool.get().await?;
+
+        // Only invites without a known status keep the reason, so that a failed recheck
+        // does not hide whether the invite was valid
+        let statement = "
+            UPDATE
+                public.invite
+            SET
+                status = $2,
+                error_code = $3
+            WHERE
+                code = $1
+                AND updated_at IS NULL;
+        ";
+
+        let params: &[&(dyn ToSql + Sync)] = &[&code, &resolution.status, &resolution.error_code];
 
         client.execute(statement, params).await?;
 
@@ -170,3 +462,67 @@ impl Database {
         Ok(())
     }
 }
+
+impl InviteStatus {
+    pub fn is_transient(&self) -> bool {
+        matches!(self, Self::NetworkError | Self::RateLimited)
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::GuildUnavailable => "guild unavailable",
+            Self::NetworkError => "network error",
+            Self::RateLimited => "rate limited",
+            Self::UnknownInvite => "expired or deleted",
+            Self::Valid => "valid",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use time::OffsetDateTime;
+
+    use crate::types::{
+        database::{Database, InviteStatus},
+        resolver::InviteResolution,
+    };
+
+    const CODE: &str = "sakura-alert-test";
+
+    #[tokio::test]
+    #[ignore = "needs a PostgreSQL database in DATABASE_URL"]
+    async fn alerts_once_per_invalid_invite() {
+        let database = Database::new().unwrap();
+        let invalid = InviteResolution::failed(InviteStatus::UnknownInvite, Some(10006));
+        let valid = InviteResolution::failed(InviteStatus::Valid, None);
+
+        database.run_migrations().await.unwrap();
+        database.insert_unchecked_invite(CODE).await.unwrap();
+
+        let mut alerts = Vec::new();
+
+        for resolution in [&invalid, &invalid, &valid, &invalid] {
+            // Posting the invite again resets it to unchecked, which should not
+            // count as a new invalid invite
+            database.insert_unchecked_invite(CODE).await.unwrap();
+            alerts.push(
+                database
+                    .insert_checked_invite(CODE, resolution, OffsetDateTime::now_utc())
+                    .await
+                    .unwrap(),
+            );
+        }
+
+        database
+            .pool
+            .get()
+            .await
+            .unwrap()
+            .execute("DELETE FROM public.invite WHERE code = $1;", &[&CODE])
+            .await
+            .unwrap();
+
+        assert_eq!(alerts, [true, false, false, true]);
+    }
+}
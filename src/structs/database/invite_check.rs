@@ -0,0 +1,202 @@
+use deadpool_postgres::GenericClient;
+use tokio_postgres::{types::ToSql, Row};
+use twilight_model::id::{marker::GuildMarker, Id};
+
+use crate::types::{
+    database::{Database, InviteCheckChannel, InviteCheckChannelStatus},
+    Result,
+};
+
+impl Database {
+    pub async fn get_latest_invite_check_channels(
+        &self,
+        guild_id: Id<GuildMarker>,
+        profile: Option<&str>,
+    ) -> Result<Option<Vec<InviteCheckChannel>>> {
+        let client = self.pool.get().await?;
+
+        let statement = "
+            WITH latest_invite_check AS (
+                SELECT
+                    event_id
+                FROM
+                    public.event_log
+                WHERE
+                    event_type = 'INVITE_CHECK_CREATE'
+                    AND (payload->>'guild_id')::INT8 = $1
+                    AND payload->>'profile' IS NOT DISTINCT FROM $2
+                    AND EXISTS (
+                        SELECT
+                            1
+                        FROM
+                            public.invite_check_channel
+                        WHERE
+                            check_id = event_id
+                    )
+                ORDER BY
+                    created_at DESC
+                LIMIT
+                    1
+            )
+            SELECT
+                public.invite_check_channel.*
+            FROM
+                public.invite_check_channel
+                JOIN latest_invite_check ON latest_invite_check.event_id = check_id
+            ORDER BY
+                category_id,
+                position;
+        ";
+
+        let params: &[&(dyn ToSql + Sync)] = &[&(guild_id.get() as i64), &profile];
+        let rows = client.query(statement, params).await?;
+
+        if rows.is_empty() {
+            return Ok(None);
+        }
+
+        Ok(Some(rows.into_iter().map(Into::into).collect()))
+    }
+
+    pub async fn insert_invite_check_channels(
+        client: &impl GenericClient,
+        check_id: i64,
+        channels: &[InviteCheckChannel],
+    ) -> Result<()> {
+        let statement = "
+            INSERT INTO
+                public.invite_check_channel (
+                    check_id,
+                    channel_id,
+                    category_id,
+                    position,
+                    valid_invites,
+                    invalid_invites,
+                    unknown_invites,
+                    temporary_invites,
+                    is_ignored,
+                    is_untracked,
+                    breaks_rules
+                )
+            SELECT
+                $1,
+                *
+            FROM
+                UNNEST(
+                    $2::INT8[],
+                    $3::INT8[],
+                    $4::INT4[],
+                    $5::INT4[],
+                    $6::INT4[],
+                    $7::INT4[],
+                    $8::INT4[],
+                    $9::BOOLEAN[],
+                    $10::BOOLEAN[],
+                    $11::BOOLEAN[]
+                );
+        ";
+
+        let mut channel_ids = Vec::with_capacity(channels.len());
+        let mut category_ids = Vec::with_capacity(channels.len());
+        let mut positions = Vec::with_capacity(channels.len());
+        let mut valid_invites = Vec::with_capacity(channels.len());
+        let mut invalid_invites = Vec::with_capacity(channels.len());
+        let mut unknown_invites = Vec::with_capacity(channels.len());
+        let mut temporary_invites = Vec::with_capacity(channels.len());
+        let mut ignored = Vec::with_capacity(channels.len());
+        let mut untracked = Vec::with_capacity(channels.len());
+        let mut breaks_rules = Vec::with_capacity(channels.len());
+
+        for channel in channels {
+            channel_ids.push(channel.channel_id.get() as i64);
+            category_ids.push(channel.category_id.get() as i64);
+            positions.push(channel.position);
+            valid_invites.push(channel.valid_invites);
+            invalid_invites.push(channel.invalid_invites);
+            unknown_invites.push(channel.unknown_invites);
+            temporary_invites.push(channel.temporary_invites);
+            ignored.push(channel.is_ignored);
+            untracked.push(channel.is_untracked);
+            breaks_rules.push(channel.breaks_rules);
+        }
+
+        let params: &[&(dyn ToSql + Sync)] = &[
+            &check_id,
+            &channel_ids,
+            &category_ids,
+            &positions,
+            &valid_invites,
+            &invalid_invites,
+            &unknown_invites,
+            &temporary_invites,
+            &ignored,
+            &untracked,
+            &breaks_rules,
+        ];
+
+        client.execute(statement, params).await?;
+
+        Ok(())
+    }
+}
+
+impl InviteCheckChannel {
+    pub fn status(&self) -> InviteCheckChannelStatus {
+        if self.is_ignored {
+            InviteCheckChannelStatus::Ignored
+        } else if self.is_untracked {
+            InviteCheckChannelStatus::Untracked
+        } else if self.unknown_invites > 0 {
+            InviteCheckChannelStatus::Unknown
+        } else if self.invalid_invites > 0 {
+            InviteCheckChannelStatus::Invalid
+        } else if self.breaks_rules {
+            InviteCheckChannelStatus::BreaksRules
+        } else if self.temporary_invites > 0 {
+            InviteCheckChannelStatus::Temporary
+        } else {
+            InviteCheckChannelStatus::Valid
+        }
+    }
+}
+
+impl From<Row> for InviteCheckChannel {
+    fn from(row: Row) -> Self {
+        Self {
+            channel_id: Id::new(row.get::<_, i64>("channel_id") as u64),
+            category_id: Id::new(row.get::<_, i64>("category_id") as u64),
+            position: row.get::<_, i32>("position"),
+            valid_invites: row.get::<_, i32>("valid_invites"),
+            invalid_invites: row.get::<_, i32>("invalid_invites"),
+            unknown_invites: row.get::<_, i32>("unknown_invites"),
+            temporary_invites: row.get::<_, i32>("temporary_invites"),
+            is_ignored: row.get::<_, bool>("is_ignored"),
+            is_untracked: row.get::<_, bool>("is_untracked"),
+            breaks_rules: row.get::<_, bool>("breaks_rules"),
+        }
+    }
+}
+
+impl InviteCheckChannelStatus {
+    pub fn emoji(&self) -> &'static str {
+        match self {
+            Self::BreaksRules => "🟠",
+            Self::Invalid => "🔴",
+            Self::Temporary => "🟡",
+            Self::Valid => "🟢",
+            _ => "⚪",
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::BreaksRules => "breaking partner rules",
+            Self::Ignored => "ignored",
+            Self::Invalid => "invalid",
+            Self::Temporary => "temporary",
+            Self::Unknown => "unknown",
+            Self::Untracked => "untracked",
+            Self::Valid => "valid",
+        }
+    }
+}
@@ -1,5 +1,5 @@
 use std::{
-    collections::{HashMap, HashSet},
+    collections::{BTreeMap, HashMap, HashSet},
     iter,
 };
 
@@ -9,28 +9,79 @@ use twilight_model::id::{
     Id,
 };
 
-use crate::types::{database::Database, Result};
+use crate::types::{
+    database::{Database, InviteLocation},
+    Result,
+};
 
 impl Database {
+    pub async fn get_invalid_invite_alert_messages(
+        &self,
+        code: &str,
+    ) -> Result<Vec<(Id<GuildMarker>, Id<ChannelMarker>, Id<MessageMarker>)>> {
+        let client = self.pool.get().await?;
+
+        let statement = "
+            SELECT
+                public.counted_message.guild_id,
+                public.counted_message.channel_id,
+                public.counted_message.message_id
+            FROM
+                public.counted_message
+                JOIN public.guild ON public.guild.guild_id = public.counted_message.guild_id
+            WHERE
+                $1 = ANY(public.counted_message.invite_codes)
+                AND public.guild.invalid_invite_alerts;
+        ";
+        let params: &[&(dyn ToSql + Sync)] = &[&code];
+        let messages = client
+            .query(statement, params)
+            .await?
+            .into_iter()
+            .map(|row| {
+                (
+                    Id::new(row.get::<_, i64>("guild_id") as u64),
+                    Id::new(row.get::<_, i64>("channel_id") as u64),
+                    Id::new(row.get::<_, i64>("message_id") as u64),
+                )
+            })
+            .collect();
+
+        Ok(messages)
+    }
+
     pub async fn insert_message(
         &self,
         guild_id: Id<GuildMarker>,
         channel_id: Id<ChannelMarker>,
         message_id: Id<MessageMarker>,
         parent_id: Id<ChannelMarker>,
-        invite_codes: HashSet<String>,
+        invite_codes: BTreeMap<String, InviteLocation>,
+        is_pinned: Option<bool>,
     ) -> Result<()> {
         let client = self.pool.get().await?;
 
+        // Message updates do not always say whether the message is pinned. The codes
+        // are sorted, so that rescanning a message stores them in the same order
         let statement = "
             INSERT INTO
-                public.message (guild_id, channel_id, message_id, parent_id, invite_codes)
+                public.message (
+                    guild_id,
+                    channel_id,
+                    message_id,
+                    parent_id,
+                    invite_codes,
+                    invite_locations,
+                    is_pinned
+                )
             VALUES
-                ($1, $2, $3, $4, $5)
+                ($1, $2, $3, $4, $5, $6, COALESCE($7, FALSE))
             ON CONFLICT (guild_id, channel_id, message_id)
             DO UPDATE
             SET
-                invite_codes = EXCLUDED.invite_codes;
+                invite_codes = EXCLUDED.invite_codes,
+                invite_locations = EXCLUDED.invite_locations,
+                is_pinned = COALESCE($7, public.message.is_pinned);
         ";
 
         let params: &[&(dyn ToSql + Sync)] = &[
@@ -38,7 +89,41 @@ impl Database {
             &(channel_id.get() as i64),
             &(message_id.get() as i64),
             &(parent_id.get() as i64),
-            &Vec::from_iter(invite_codes),
+            &invite_codes.keys().collect::<Vec<&String>>(),
+            &serde_json::to_value(&invite_codes)?,
+            &is_pinned,
+        ];
+
+        client.execute(statement, params).await?;
+
+        Ok(())
+    }
+
+    pub async fn insert_pinned_messages(
+        &self,
+        guild_id: Id<GuildMarker>,
+        channel_id: Id<ChannelMarker>,
+        message_ids: Vec<Id<MessageMarker>>,
+    ) -> Result<()> {
+        let client = self.pool.get().await?;
+
+        let statement = "
+            UPDATE
+                public.message
+            SET
+                is_pinned = message_id = ANY($3)
+            WHERE
+                guild_id = $1
+                AND channel_id = $2;
+        ";
+
+        let params: &[&(dyn ToSql + Sync)] = &[
+            &(guild_id.get() as i64),
+            &(channel_id.get() as i64),
+            &message_ids
+                .into_iter()
+                .map(|message_id| message_id.get() as i64)
+                .collect::<Vec<i64>>(),
         ];
 
         client.execute(statement, params).await?;
@@ -155,3 +240,29 @@ impl Database {
         Ok(removed_ids)
     }
 }
+
+impl InviteLocation {
+    pub fn description(&self) -> String {
+        match self {
+            Self::Attachment {
+                filename,
+            } => format!("attachment \"{filename}\""),
+            Self::Button {
+                label,
+            } => {
+                label.as_ref().map_or("link button".to_owned(), |label| {
+                    format!("button \"{label}\"")
+                })
+            }
+            Self::Content => "message content".to_owned(),
+            Self::EmbedAuthor => "embed author".to_owned(),
+            Self::EmbedDescription => "embed description".to_owned(),
+            Self::EmbedField {
+                name,
+            } => format!("embed field \"{name}\""),
+            Self::EmbedFooter => "embed footer".to_owned(),
+            Self::EmbedTitle => "embed title".to_owned(),
+            Self::EmbedUrl => "embed link".to_owned(),
+        }
+    }
+}
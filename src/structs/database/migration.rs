@@ -0,0 +1,228 @@
+use std::collections::HashMap;
+
+use sha2::{Digest, Sha256};
+use tokio_postgres::types::ToSql;
+
+use crate::{
+    types::{
+        database::{Database, Migration},
+        Result,
+    },
+    utility::error::Error,
+};
+
+// Migrations are applied in order and must never be edited once released. Add
+// a new file to `migrations/` and a new entry here instead.
+const MIGRATIONS: &[Migration] = &[
+    Migration {
+        version: 1,
+        name: "create_tables",
+        statement: include_str!("../../../migrations/0001_create_tables.sql"),
+    },
+    Migration {
+        version: 2,
+        name: "index_invite_check_events",
+        statement: include_str!("../../../migrations/0002_index_invite_check_events.sql"),
+    },
+    Migration {
+        version: 3,
+        name: "create_invite_check_channel",
+        statement: include_str!("../../../migrations/0003_create_invite_check_channel.sql"),
+    },
+    Migration {
+        version: 4,
+        name: "add_guild_check_schedule",
+        statement: include_str!("../../../migrations/0004_add_guild_check_schedule.sql"),
+    },
+    Migration {
+        version: 5,
+        name: "index_invite_updated_at",
+        statement: include_str!("../../../migrations/0005_index_invite_updated_at.sql"),
+    },
+    Migration {
+        version: 6,
+        name: "add_invite_status",
+        statement: include_str!("../../../migrations/0006_add_invite_status.sql"),
+    },
+    Migration {
+        version: 7,
+        name: "add_invite_target_guild",
+        statement: include_str!("../../../migrations/0007_add_invite_target_guild.sql"),
+    },
+    Migration {
+        version: 8,
+        name: "add_require_permanent_invites",
+        statement: include_str!("../../../migrations/0008_add_require_permanent_invites.sql"),
+    },
+    Migration {
+        version: 9,
+        name: "add_guild_expiry_warnings",
+        statement: include_str!("../../../migrations/0009_add_guild_expiry_warnings.sql"),
+    },
+    Migration {
+        version: 10,
+        name: "add_invalid_invite_alerts",
+        statement: include_str!("../../../migrations/0010_add_invalid_invite_alerts.sql"),
+    },
+    Migration {
+        version: 11,
+        name: "add_guild_scan_settings",
+        statement: include_str!("../../../migrations/0011_add_guild_scan_settings.sql"),
+    },
+    Migration {
+        version: 12,
+        name: "add_invite_counting_mode",
+        statement: include_str!("../../../migrations/0012_add_invite_counting_mode.sql"),
+    },
+    Migration {
+        version: 13,
+        name: "add_message_invite_locations",
+        statement: include_str!("../../../migrations/0013_add_message_invite_locations.sql"),
+    },
+    Migration {
+        version: 14,
+        name: "create_channel_rule",
+        statement: include_str!("../../../migrations/0014_create_channel_rule.sql"),
+    },
+    Migration {
+        version: 15,
+        name: "add_guild_ignore_rules",
+        statement: include_str!("../../../migrations/0015_add_guild_ignore_rules.sql"),
+    },
+    Migration {
+        version: 16,
+        name: "add_guild_tracked_channels",
+        statement: include_str!("../../../migrations/0016_add_guild_tracked_channels.sql"),
+    },
+    Migration {
+        version: 17,
+        name: "create_check_profile",
+        statement: include_str!("../../../migrations/0017_create_check_profile.sql"),
+    },
+    Migration {
+        version: 18,
+        name: "add_invite_is_temporary",
+        statement: include_str!("../../../migrations/0018_add_invite_is_temporary.sql"),
+    },
+    Migration {
+        version: 19,
+        name: "add_invite_check_channel_breaks_rules",
+        statement: include_str!(
+            "../../../migrations/0019_add_invite_check_channel_breaks_rules.sql"
+        ),
+    },
+    Migration {
+        version: 20,
+        name: "add_invite_alerted_at",
+        statement: include_str!("../../../migrations/0020_add_invite_alerted_at.sql"),
+    },
+    Migration {
+        version: 21,
+        name: "add_check_profile_rules",
+        statement: include_str!("../../../migrations/0021_add_check_profile_rules.sql"),
+    },
+];
+
+// "SAKURA" in ASCII, so that only one instance applies migrations at a time.
+const MIGRATION_LOCK_KEY: i64 = 0x5341_4B55_5241;
+
+impl Database {
+    pub async fn run_migrations(&self) -> Result<()> {
+        let mut client = self.pool.get().await?;
+        let transaction = client.transaction().await?;
+
+        let statement = "
+            CREATE TABLE IF NOT EXISTS public.schema_migrations (
+                version INT4 PRIMARY KEY,
+                name TEXT NOT NULL,
+                checksum TEXT NOT NULL,
+                applied_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT CURRENT_TIMESTAMP
+            );
+        ";
+
+        transaction
+            .execute("SELECT pg_advisory_xact_lock($1);", &[&MIGRATION_LOCK_KEY])
+            .await?;
+        transaction.batch_execute(statement).await?;
+
+        let statement = "
+            SELECT
+                version,
+                checksum
+            FROM
+                public.schema_migrations;
+        ";
+        let params: &[&(dyn ToSql + Sync)] = &[];
+        let applied_checksums = transaction
+            .query(statement, params)
+            .await?
+            .into_iter()
+            .map(|row| {
+                (
+                    row.get::<_, i32>("version"),
+                    row.get::<_, String>("checksum"),
+                )
+            })
+            .collect::<HashMap<i32, String>>();
+
+        if let Some(unknown_version) = applied_checksums
+            .keys()
+            .filter(|version| {
+                !MIGRATIONS
+                    .iter()
+                    .any(|migration| migration.version.eq(*version))
+            })
+            .max()
+        {
+            return Err(Error::Migration(format!(
+                "the database has migration {unknown_version} applied, which this build does not know about"
+            )));
+        }
+
+        for migration in MIGRATIONS {
+            let checksum = migration.checksum();
+
+            match applied_checksums.get(&migration.version) {
+                Some(applied_checksum) if applied_checksum.eq(&checksum) => continue,
+                Some(_) => {
+                    return Err(Error::Migration(format!(
+                        "migration {} ({}) has changed since it was applied",
+                        migration.version, migration.name
+                    )))
+                }
+                None => {}
+            }
+
+            transaction.batch_execute(migration.statement).await?;
+
+            let statement = "
+                INSERT INTO
+                    public.schema_migrations (version, name, checksum)
+                VALUES
+                    ($1, $2, $3);
+            ";
+            let params: &[&(dyn ToSql + Sync)] = &[&migration.version, &migration.name, &checksum];
+
+            transaction.execute(statement, params).await?;
+
+            tracing::info!(
+                "Applied migration {} ({})",
+                migration.version,
+                migration.name
+            );
+        }
+
+        transaction.commit().await?;
+
+        Ok(())
+    }
+}
+
+impl Migration {
+    pub fn checksum(&self) -> String {
+        Sha256::digest(self.statement.as_bytes())
+            .iter()
+            .map(|byte| format!("{byte:02x}"))
+            .collect()
+    }
+}
@@ -1,4 +1,10 @@
+pub mod alert;
 pub mod cache;
+pub mod check;
 pub mod context;
 pub mod database;
 pub mod interaction;
+pub mod resolver;
+pub mod scan;
+pub mod shortener;
+pub mod thread;
@@ -0,0 +1,164 @@
+use std::{sync::atomic::Ordering, time::Duration};
+
+use time::OffsetDateTime;
+use tokio::time::sleep;
+use twilight_http::{
+    api_error::ApiError,
+    error::{Error as HttpError, ErrorType},
+};
+use twilight_http_ratelimiting::request::Path;
+
+use crate::types::{
+    context::Context,
+    database::{InviteStatus, InviteTarget},
+    resolver::InviteResolution,
+};
+
+const INVALID_FORM_BODY_ERROR_CODE: u64 = 50035;
+const MAX_ATTEMPTS: u8 = 3;
+const UNKNOWN_GUILD_ERROR_CODE: u64 = 10004;
+const UNKNOWN_INVITE_ERROR_CODE: u64 = 10006;
+
+impl Context {
+    pub async fn resolve_invite(
+        &self,
+        code: &str,
+    ) -> InviteResolution {
+        let mut resolution = InviteResolution::failed(InviteStatus::RateLimited, None);
+
+        for _ in 0 .. MAX_ATTEMPTS {
+            self.wait_for_invite_bucket().await;
+
+            let error = match self.http.invite(code).with_counts().with_expiration().await {
+                Ok(response) => {
+                    let Ok(invite) = response.model().await else {
+                        return InviteResolution::failed(InviteStatus::NetworkError, None);
+                    };
+                    let Some(invite_guild) = invite.guild else {
+                        return InviteResolution::failed(InviteStatus::GuildUnavailable, None);
+                    };
+                    // Discord reports unlimited age and uses as 0
+                    let is_temporary = invite.expires_at.is_some()
+                        || invite.max_age.is_some_and(|max_age| max_age > 0)
+                        || invite.max_uses.is_some_and(|max_uses| max_uses > 0);
+                    let mut is_permalink = !is_temporary;
+
+                    if let Some(vanity_url_code) = invite_guild.vanity_url_code {
+                        is_permalink = is_permalink && vanity_url_code.eq(code);
+                    }
+
+                    let expires_at = invite.expires_at.and_then(|timestamp| {
+                        OffsetDateTime::from_unix_timestamp(timestamp.as_secs()).ok()
+                    });
+
+                    return InviteResolution {
+                        error_code: None,
+                        expires_at,
+                        is_permalink,
+                        is_temporary,
+                        status: InviteStatus::Valid,
+                        target: Some(InviteTarget {
+                            guild_id: invite_guild.id,
+                            name: invite_guild.name,
+                            member_count: invite
+                                .approximate_member_count
+                                .map(|member_count| member_count as i32),
+                            online_count: invite
+                                .approximate_presence_count
+                                .map(|online_count| online_count as i32),
+                        }),
+                    };
+                }
+                Err(error) => error,
+            };
+
+            let (status, error_code) = get_failure(&error);
+
+            if status != InviteStatus::RateLimited {
+                return InviteResolution::failed(status, error_code);
+            }
+
+            resolution = InviteResolution::failed(status, error_code);
+
+            self.invite_rate_limited.store(true, Ordering::Relaxed);
+            sleep(get_retry_after(&error)).await;
+        }
+
+        resolution
+    }
+
+    // Returns how many requests are left in the bucket, which is unknown until the
+    // first invite has been requested
+    pub async fn wait_for_invite_bucket(&self) -> Option<u64> {
+        let ratelimiter = self.http.ratelimiter()?;
+        let Ok(Some(bucket)) = ratelimiter.bucket(&Path::InvitesCode).await else {
+            return None;
+        };
+
+        if bucket.remaining() > 0 {
+            return Some(bucket.remaining());
+        }
+
+        if let Some(time_remaining) = bucket.time_remaining() {
+            sleep(time_remaining).await;
+        }
+
+        Some(bucket.limit())
+    }
+}
+
+impl InviteResolution {
+    pub fn failed(
+        status: InviteStatus,
+        error_code: Option<i64>,
+    ) -> Self {
+        Self {
+            error_code,
+            expires_at: None,
+            is_permalink: false,
+            is_temporary: false,
+            status,
+            target: None,
+        }
+    }
+}
+
+fn get_failure(error: &HttpError) -> (InviteStatus, Option<i64>) {
+    let ErrorType::Response {
+        error,
+        status,
+        ..
+    } = error.kind()
+    else {
+        return (InviteStatus::NetworkError, None);
+    };
+
+    match error {
+        ApiError::Ratelimited(_) => (InviteStatus::RateLimited, Some(status.get() as i64)),
+        ApiError::General(general) => {
+            let status = match general.code {
+                UNKNOWN_GUILD_ERROR_CODE => InviteStatus::GuildUnavailable,
+                UNKNOWN_INVITE_ERROR_CODE | INVALID_FORM_BODY_ERROR_CODE => {
+                    InviteStatus::UnknownInvite
+                }
+                _ if status.get() == 404 => InviteStatus::UnknownInvite,
+                _ if status.get() == 429 => InviteStatus::RateLimited,
+                _ => InviteStatus::NetworkError,
+            };
+
+            (status, Some(general.code as i64))
+        }
+        _ if status.get() == 404 => (InviteStatus::UnknownInvite, Some(status.get() as i64)),
+        _ => (InviteStatus::NetworkError, Some(status.get() as i64)),
+    }
+}
+
+fn get_retry_after(error: &HttpError) -> Duration {
+    match error.kind() {
+        ErrorType::Response {
+            error: ApiError::Ratelimited(ratelimited),
+            ..
+        } => Duration::from_secs_f64(ratelimited.retry_after.max(1.0)),
+        _ => Duration::from_secs(1),
+    }
+}
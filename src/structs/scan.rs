@@ -0,0 +1,156 @@
+use std::{cmp::min, time::Duration};
+
+use tokio::time::sleep;
+use twilight_model::{
+    channel::Message,
+    id::{marker::ChannelMarker, Id},
+};
+
+use crate::{
+    types::{cache::GuildJobGuard, context::Context, database, Result},
+    utility::error::Error,
+};
+
+// Discord returns at most this many messages per request
+const MESSAGES_PER_PAGE: u16 = 100;
+
+impl Context {
+    // Every channel in the category has to be visible before any of them are
+    // scanned
+    pub async fn scan_category(
+        &self,
+        database_guild: &database::Guild,
+        guild_job: &GuildJobGuard<'_>,
+        category_id: Id<ChannelMarker>,
+    ) -> Result<()> {
+        let Some(cached_guild) = self.cache.get_guild(database_guild.guild_id) else {
+            return Err(Error::Custom("Please kick and invite Sakura.".to_owned()));
+        };
+        let mut channel_ids_to_process = Vec::new();
+        let mut invisible_channels = Vec::new();
+
+        let forum_ids = cached_guild
+            .channel_ids
+            .read()
+            .clone()
+            .into_iter()
+            .filter(|channel_id| {
+                self.cache.get_channel(*channel_id).is_some_and(|channel| {
+                    channel.is_forum() && channel.parent_id.eq(&Some(category_id))
+                })
+            })
+            .collect::<Vec<Id<ChannelMarker>>>();
+
+        for forum_id in forum_ids {
+            self.cache_archived_threads(forum_id).await?;
+        }
+
+        for channel_id in cached_guild.channel_ids.read().clone().into_iter() {
+            let channel = match self.cache.get_channel(channel_id) {
+                None => continue,
+                Some(channel) => channel,
+            };
+
+            if self
+                .cache
+                .get_category_id(&channel)
+                .is_none_or(|parent_id| !parent_id.eq(&category_id))
+            {
+                continue;
+            }
+
+            if !self.cache.has_minimum_channel_permissions(channel_id) {
+                invisible_channels.push(format!("- <#{channel_id}>"))
+            } else if !channel.is_forum() {
+                channel_ids_to_process.push(channel_id)
+            }
+        }
+
+        if !invisible_channels.is_empty() {
+            return Err(Error::Custom(format!("Sakura is unable to check the following channels:\n{}\nPlease give permission for Sakura to read these channels and add the category again.", invisible_channels.join("\n")),));
+        }
+
+        for channel_id in channel_ids_to_process {
+            sleep(Duration::from_millis(1000)).await;
+            guild_job.ensure_not_cancelled()?;
+
+            self.scan_channel(database_guild, channel_id, category_id)
+                .await?;
+        }
+
+        Ok(())
+    }
+
+    pub async fn scan_channel(
+        &self,
+        database_guild: &database::Guild,
+        channel_id: Id<ChannelMarker>,
+        parent_id: Id<ChannelMarker>,
+    ) -> Result<()> {
+        let messages = self
+            .get_channel_messages(channel_id, database_guild.scan_depth as u16)
+            .await?;
+
+        for message in messages {
+            if !database_guild.is_scanned_author(message.author.id) {
+                continue;
+            }
+
+            let invite_codes = self
+                .get_message_invite_codes(
+                    &message.content,
+                    &message.embeds,
+                    &message.components,
+                    &message.attachments,
+                )
+                .await;
+
+            for invite_code in invite_codes.keys() {
+                self.database.insert_unchecked_invite(invite_code).await?;
+            }
+
+            self.database
+                .insert_message(
+                    database_guild.guild_id,
+                    channel_id,
+                    message.id,
+                    parent_id,
+                    invite_codes,
+                    Some(message.pinned),
+                )
+                .await?;
+        }
+
+        Ok(())
+    }
+
+    async fn get_channel_messages(
+        &self,
+        channel_id: Id<ChannelMarker>,
+        limit: u16,
+    ) -> Result<Vec<Message>> {
+        let mut messages: Vec<Message> = Vec::new();
+
+        while messages.len() < limit as usize {
+            let page_limit = min(limit - messages.len() as u16, MESSAGES_PER_PAGE);
+            let request = self.http.channel_messages(channel_id);
+            let page = match messages.last() {
+                Some(oldest_message) => {
+                    request.before(oldest_message.id).limit(page_limit)?.await?
+                }
+                None => request.limit(page_limit)?.await?,
+            }
+            .model()
+            .await?;
+            let is_last_page = page.len() < page_limit as usize;
+
+            messages.extend(page);
+
+            if is_last_page {
+                break;
+            }
+        }
+
+        Ok(messages)
+    }
+}
@@ -0,0 +1,378 @@
+use std::{
+    collections::{BTreeMap, HashMap},
+    time::{Duration, Instant},
+};
+
+use fancy_regex::{escape, Regex};
+use hyper::{
+    header::{LOCATION, USER_AGENT},
+    Body,
+    Client,
+    Request,
+    Uri,
+};
+use hyper_rustls::HttpsConnectorBuilder;
+use parking_lot::RwLock;
+use tokio::time::timeout;
+use twilight_model::channel::{
+    message::{component::Component, Embed},
+    Attachment,
+};
+
+use crate::{
+    types::{
+        context::Context,
+        database::InviteLocation,
+        shortener::{InviteShortener, ShortenedLink},
+    },
+    utility::{
+        invite::{normalize_invite_text, parse_invite_codes},
+        message::{get_invite_codes, get_invite_sources},
+    },
+};
+
+const INVITE_DOMAINS: &[&str] = &["discord.com", "discord.gg", "discordapp.com"];
+const MAX_REDIRECTS: u8 = 5;
+const SHORTENED_LINK_CACHE_AGE: Duration = Duration::from_secs(60 * 60);
+const SHORTENED_LINK_TIMEOUT: Duration = Duration::from_secs(5);
+
+impl Context {
+    pub async fn get_message_invite_codes(
+        &self,
+        content: &str,
+        embeds: &[Embed],
+        components: &[Component],
+        attachments: &[Attachment],
+    ) -> BTreeMap<String, InviteLocation> {
+        let mut invite_codes = get_invite_codes(content, embeds, components, attachments);
+        let Some(shortener) = &self.shortener else {
+            return invite_codes;
+        };
+
+        for (text, location) in get_invite_sources(content, embeds, components, attachments) {
+            for link in shortener.find_links(text) {
+                if let Some(code) = shortener.resolve(&link).await {
+                    invite_codes.entry(code).or_insert_with(|| location.clone());
+                }
+            }
+        }
+
+        invite_codes
+    }
+}
+
+impl InviteShortener {
+    pub fn find_links(
+        &self,
+        text: &str,
+    ) -> Vec<String> {
+        let normalized = normalize_invite_text(text);
+        let mut links = Vec::new();
+
+        for captures in self.link_regex.captures_iter(&normalized).flatten() {
+            if let (Some(domain), Some(path)) = (captures.name("domain"), captures.name("path")) {
+                let link = format!(
+                    "https://{}/{}",
+                    domain.as_str().to_lowercase(),
+                    path.as_str()
+                );
+
+                if !links.contains(&link) {
+                    links.push(link);
+                }
+            }
+        }
+
+        links
+    }
+
+    async fn follow_redirects(
+        &self,
+        link: &str,
+    ) -> Option<String> {
+        let mut uri = link.parse::<Uri>().ok()?;
+
+        for _ in 0 .. MAX_REDIRECTS {
+            // Only allowlisted services are ever contacted
+            if !uri
+                .host()
+                .is_some_and(|host| is_domain(host, &self.domains))
+            {
+                return None;
+            }
+
+            let request = Request::get(uri.clone())
+                .header(USER_AGENT, "Sakura")
+                .body(Body::empty())
+                .ok()?;
+            let response = self.client.request(request).await.ok()?;
+
+            if !response.status().is_redirection() {
+                return None;
+            }
+
+            let location = response
+                .headers()
+                .get(LOCATION)?
+                .to_str()
+                .ok()?
+                .parse::<Uri>()
+                .ok()?;
+
+            uri = if location.host().is_some() {
+                location
+            } else {
+                Uri::builder()
+                    .scheme(uri.scheme()?.clone())
+                    .authority(uri.authority()?.clone())
+                    .path_and_query(location.path_and_query()?.clone())
+                    .build()
+                    .ok()?
+            };
+
+            if uri
+                .host()
+                .is_some_and(|host| is_domain(host, INVITE_DOMAINS))
+            {
+                return parse_invite_codes(&uri.to_string()).into_iter().next();
+            }
+        }
+
+        None
+    }
+
+    pub fn new(domains: Vec<String>) -> Self {
+        let domain_pattern = domains
+            .iter()
+            .map(|domain| escape(domain).into_owned())
+            .collect::<Vec<String>>()
+            .join("|");
+        let link_regex = Regex::new(&format!(
+            r"(?i)(?<![a-z0-9.-])(?:https?:\/\/)?(?:www\.)?(?<domain>{domain_pattern})\/(?<path>[a-z0-9_-]+)"
+        ))
+        .unwrap();
+        let connector = HttpsConnectorBuilder::new().with_native_roots();
+        // Redirects to plain HTTP are refused, but the stub server in the tests does
+        // not speak TLS
+        #[cfg(not(test))]
+        let connector = connector.https_only();
+        #[cfg(test)]
+        let connector = connector.https_or_http();
+        let connector = connector.enable_http1().build();
+
+        Self {
+            cache: RwLock::new(HashMap::new()),
+            client: Client::builder().build(connector),
+            domains,
+            link_regex,
+            timeout: SHORTENED_LINK_TIMEOUT,
+        }
+    }
+
+    pub async fn resolve(
+        &self,
+        link: &str,
+    ) -> Option<String> {
+        if let Some(shortened_link) = self.cache.read().get(link) {
+            if shortened_link.resolved_at.elapsed() < SHORTENED_LINK_CACHE_AGE {
+                return shortened_link.code.clone();
+            }
+        }
+
+        // Dead and slow links are cached as well, so that they are not retried for
+        // every message
+        let code = timeout(self.timeout, self.follow_redirects(link))
+            .await
+            .ok()
+            .flatten();
+        let mut cache = self.cache.write();
+
+        cache.retain(|_, shortened_link| {
+            shortened_link.resolved_at.elapsed() < SHORTENED_LINK_CACHE_AGE
+        });
+        cache.insert(
+            link.to_owned(),
+            ShortenedLink {
+                code: code.clone(),
+                resolved_at: Instant::now(),
+            },
+        );
+
+        code
+    }
+}
+
+fn is_domain(
+    host: &str,
+    domains: &[impl AsRef<str>],
+) -> bool {
+    let host = host.to_lowercase();
+
+    domains.iter().any(|domain| {
+        let domain = domain.as_ref();
+
+        host == domain || host.ends_with(&format!(".{domain}"))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        convert::Infallible,
+        net::SocketAddr,
+        sync::atomic::{AtomicUsize, Ordering},
+        time::Duration,
+    };
+
+    use hyper::{
+        header::LOCATION,
+        service::{make_service_fn, service_fn},
+        Body,
+        Request,
+        Response,
+        Server,
+        StatusCode,
+    };
+    use tokio::time::sleep;
+
+    use super::InviteShortener;
+    use crate::utility::constants::INVITE_SHORTENER_DOMAINS;
+
+    static COUNTED_REQUESTS: AtomicUsize = AtomicUsize::new(0);
+
+    fn redirect(location: &str) -> Response<Body> {
+        Response::builder()
+            .status(StatusCode::FOUND)
+            .header(LOCATION, location)
+            .body(Body::empty())
+            .unwrap()
+    }
+
+    async fn handle_request(request: Request<Body>) -> Result<Response<Body>, Infallible> {
+        let response = match request.uri().path() {
+            "/counted" => {
+                COUNTED_REQUESTS.fetch_add(1, Ordering::Relaxed);
+
+                redirect("https://discord.gg/counted")
+            }
+            "/elsewhere" => redirect("https://example.com/sakura"),
+            "/hop" => redirect("/sakura"),
+            "/loop" => redirect("/loop"),
+            "/sakura" => redirect("https://discord.com/invite/sakura?event=1"),
+            "/slow" => {
+                sleep(Duration::from_secs(10)).await;
+
+                redirect("https://discord.gg/slow")
+            }
+            _ => {
+                Response::builder()
+                    .status(StatusCode::NOT_FOUND)
+                    .body(Body::empty())
+                    .unwrap()
+            }
+        };
+
+        Ok(response)
+    }
+
+    fn start_stub_server() -> SocketAddr {
+        let make_service =
+            make_service_fn(|_| async { Ok::<_, Infallible>(service_fn(handle_request)) });
+        let server = Server::bind(&SocketAddr::from(([127, 0, 0, 1], 0))).serve(make_service);
+        let address = server.local_addr();
+
+        tokio::spawn(server);
+
+        address
+    }
+
+    fn stub_shortener() -> InviteShortener {
+        InviteShortener::new(vec!["127.0.0.1".to_owned()])
+    }
+
+    #[tokio::test]
+    async fn caches_resolved_links() {
+        let address = start_stub_server();
+        let shortener = stub_shortener();
+        let link = format!("http://{address}/counted");
+
+        assert_eq!(shortener.resolve(&link).await.as_deref(), Some("counted"));
+        assert_eq!(shortener.resolve(&link).await.as_deref(), Some("counted"));
+        assert_eq!(COUNTED_REQUESTS.load(Ordering::Relaxed), 1);
+    }
+
+    #[test]
+    fn finds_allowlisted_links() {
+        let shortener = InviteShortener::new(INVITE_SHORTENER_DOMAINS.clone());
+
+        assert_eq!(
+            shortener.find_links(
+                "Join dsc.gg/sakura or https://www.Invite.gg/Art-Club\nnotdsc.gg/fake https://discord.gg/real dsc\\.gg/sakura"
+            ),
+            vec!["https://dsc.gg/sakura", "https://invite.gg/Art-Club"]
+        );
+    }
+
+    #[tokio::test]
+    async fn follows_relative_redirects() {
+        let address = start_stub_server();
+
+        assert_eq!(
+            stub_shortener()
+                .resolve(&format!("http://{address}/hop"))
+                .await
+                .as_deref(),
+            Some("sakura")
+        );
+    }
+
+    #[tokio::test]
+    async fn ignores_domains_not_allowlisted() {
+        let address = start_stub_server();
+        let shortener = InviteShortener::new(vec!["dsc.gg".to_owned()]);
+
+        assert_eq!(
+            shortener.resolve(&format!("http://{address}/sakura")).await,
+            None
+        );
+    }
+
+    #[tokio::test]
+    async fn ignores_links_without_invites() {
+        let address = start_stub_server();
+        let shortener = stub_shortener();
+
+        for path in ["elsewhere", "loop", "missing"] {
+            assert_eq!(
+                shortener.resolve(&format!("http://{address}/{path}")).await,
+                None
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn resolves_redirects_to_invites() {
+        let address = start_stub_server();
+
+        assert_eq!(
+            stub_shortener()
+                .resolve(&format!("http://{address}/sakura"))
+                .await
+                .as_deref(),
+            Some("sakura")
+        );
+    }
+
+    #[tokio::test]
+    async fn times_out_slow_links() {
+        let address = start_stub_server();
+        let mut shortener = stub_shortener();
+
+        shortener.timeout = Duration::from_millis(100);
+
+        assert_eq!(
+            shortener.resolve(&format!("http://{address}/slow")).await,
+            None
+        );
+    }
+}
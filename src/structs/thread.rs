@@ -0,0 +1,71 @@
+use twilight_model::id::{
+    marker::{ChannelMarker, GuildMarker},
+    Id,
+};
+
+use crate::types::{context::Context, Result};
+
+// Discord returns at most this many archived threads per request
+const ARCHIVED_THREADS_PER_PAGE: u64 = 100;
+
+impl Context {
+    // The gateway only sends active threads, so archived forum posts have to be
+    // fetched before a forum is checked
+    pub async fn cache_archived_threads(
+        &self,
+        forum_id: Id<ChannelMarker>,
+    ) -> Result<()> {
+        if !self.cache.has_minimum_channel_permissions(forum_id) {
+            return Ok(());
+        }
+
+        let mut before: Option<String> = None;
+
+        loop {
+            let request = self
+                .http
+                .public_archived_threads(forum_id)
+                .limit(ARCHIVED_THREADS_PER_PAGE);
+            let listing = match &before {
+                Some(before) => request.before(before).await?,
+                None => request.await?,
+            }
+            .model()
+            .await?;
+
+            before = listing
+                .threads
+                .last()
+                .and_then(|thread| thread.thread_metadata.as_ref())
+                .map(|thread_metadata| thread_metadata.archive_timestamp.iso_8601().to_string());
+
+            for thread in listing.threads {
+                self.cache.insert_channel(thread);
+            }
+
+            if !listing.has_more.unwrap_or_default() || before.is_none() {
+                break;
+            }
+        }
+
+        Ok(())
+    }
+
+    pub async fn remove_thread(
+        &self,
+        guild_id: Id<GuildMarker>,
+        thread_id: Id<ChannelMarker>,
+    ) -> Result<()> {
+        self.cache.remove_channel(thread_id);
+        self.database.remove_channel(guild_id, thread_id).await?;
+        self.database.remove_channel_messages(thread_id).await?;
+        self.database
+            .remove_channel_rules(guild_id, thread_id)
+            .await?;
+        self.database
+            .remove_check_profile_channel(guild_id, thread_id)
+            .await?;
+
+        Ok(())
+    }
+}
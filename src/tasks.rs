@@ -1,34 +1,51 @@
-use std::{sync::Arc, time::Duration};
+use std::{
+    cmp::{max, min},
+    sync::{atomic::Ordering, Arc},
+    time::Duration,
+};
 
-use time::OffsetDateTime;
-use tokio::time::sleep;
-use tokio_cron_scheduler::{Job, JobScheduler};
+use time::{Duration as TimeDuration, OffsetDateTime};
+use tokio::{spawn, sync::Semaphore, task::JoinSet, time::sleep};
+use tokio_cron_scheduler::Job;
+use twilight_model::id::{
+    marker::{ChannelMarker, GuildMarker},
+    Id,
+};
+use twilight_util::builder::embed::EmbedBuilder;
+use uuid::Uuid;
 
 use crate::{
-    types::{cache::GuildUpdate, context::Context, Result},
-    utility::message::get_invite_codes,
+    types::{
+        cache::GuildJobKind,
+        check::CheckOptions,
+        context::{Context, ScheduledCheck},
+        Result,
+    },
+    utility::{
+        constants::{INVITE_EXPIRY_MARGIN, INVITE_REVALIDATION_AGE},
+        message::get_list_embed,
+        time::get_cron_expression,
+    },
 };
 
+const EXPIRY_WARNING_SCHEDULE: &str = "0 0 12 * * *";
+const INVALID_INVITE_ALERT_INTERVAL: Duration = Duration::from_secs(10);
+const INVITE_RESOLVER_BATCH_SIZE: i64 = 100;
+const INVITE_RESOLVER_MAX_CONCURRENCY: usize = 16;
+const INVITE_RESOLVER_IDLE_INTERVAL: Duration = Duration::from_secs(20);
+const INVITE_RESOLVER_MAX_BACKOFF: Duration = Duration::from_secs(300);
+
 #[cold]
 pub async fn handle_tasks(context: Arc<Context>) -> Result<()> {
     // Wait while the jobs run
     sleep(Duration::from_secs(60)).await;
 
-    let scheduler: JobScheduler = JobScheduler::new().await?;
-    let unchecked_invites_task_context = context.clone();
+    let scheduler = context.scheduler.clone();
     let recycle_invites_task_context = context.clone();
+    let expiry_warnings_task_context = context.clone();
 
-    scheduler
-        .add(Job::new_async("*/20 1-59 * * * *", move |_uuid, _lock| {
-            let unchecked_invites_task_context = unchecked_invites_task_context.clone();
-
-            Box::pin(async move {
-                handle_unchecked_invites_task(unchecked_invites_task_context)
-                    .await
-                    .unwrap();
-            })
-        })?)
-        .await?;
+    spawn(handle_invite_resolver_task(context.clone()));
+    spawn(handle_invalid_invite_alerts_task(context.clone()));
 
     scheduler
         .add(Job::new_async("0 0 * * * *", move |_uuid, _lock| {
@@ -42,123 +59,440 @@ pub async fn handle_tasks(context: Arc<Context>) -> Result<()> {
         })?)
         .await?;
 
+    scheduler
+        .add(Job::new_async(
+            EXPIRY_WARNING_SCHEDULE,
+            move |_uuid, _lock| {
+                let expiry_warnings_task_context = expiry_warnings_task_context.clone();
+
+                Box::pin(async move {
+                    handle_expiry_warnings_task(expiry_warnings_task_context)
+                        .await
+                        .unwrap();
+                })
+            },
+        )?)
+        .await?;
+
+    for (guild_id, check_schedule) in context.database.get_check_schedules().await? {
+        schedule_invite_check(context.clone(), guild_id, None, &check_schedule).await?;
+    }
+
+    for (guild_id, profile, check_schedule) in
+        context.database.get_check_profile_schedules().await?
+    {
+        schedule_invite_check(context.clone(), guild_id, Some(profile), &check_schedule).await?;
+    }
+
     // Start the scheduler
     scheduler.start().await?;
 
     Ok(())
 }
 
-async fn handle_unchecked_invites_task(context: Arc<Context>) -> Result<()> {
-    if let Ok(unchecked_invite_codes) = context.database.get_unchecked_invites().await {
-        for unchecked_invite_code in unchecked_invite_codes {
-            let (is_permalink, is_valid, expires_at) = if let Ok(response) = context
-                .http
-                .invite(&unchecked_invite_code)
-                .with_expiration()
+// Each check profile has its own schedule, separate from the guild's
+pub async fn schedule_invite_check(
+    context: Arc<Context>,
+    guild_id: Id<GuildMarker>,
+    profile: Option<String>,
+    check_schedule: &str,
+) -> Result<()> {
+    unschedule_invite_check(&context, guild_id, profile.as_deref()).await?;
+
+    let scheduled_invite_check_task_context = context.clone();
+    let scheduled_profile = profile.clone();
+    let job = Job::new_async(
+        get_cron_expression(check_schedule).as_str(),
+        move |_uuid, _lock| {
+            let scheduled_invite_check_task_context = scheduled_invite_check_task_context.clone();
+            let scheduled_profile = scheduled_profile.clone();
+
+            Box::pin(async move {
+                handle_scheduled_invite_check_task(
+                    scheduled_invite_check_task_context,
+                    guild_id,
+                    scheduled_profile,
+                )
                 .await
-            {
-                let invite = response.model().await?;
-                let mut is_permalink = invite.expires_at.is_none()
-                    && invite.max_age.is_none()
-                    && invite.max_uses.is_none();
-
-                if let Some(invite_guild) = invite.guild {
-                    if let Some(vanity_url_code) = invite_guild.vanity_url_code {
-                        is_permalink = is_permalink && vanity_url_code.eq(&unchecked_invite_code);
-                    }
-                }
+                .unwrap();
+            })
+        },
+    )?;
+    let job_id = context.scheduler.add(job).await?;
 
-                let expires_at = invite.expires_at.map(|timestamp| {
-                    OffsetDateTime::from_unix_timestamp(timestamp.as_secs()).unwrap()
-                });
+    context.scheduled_checks.write().insert(
+        ScheduledCheck {
+            guild_id,
+            profile,
+        },
+        job_id,
+    );
 
-                (is_permalink, true, expires_at)
-            } else {
-                (false, false, None)
-            };
+    Ok(())
+}
 
-            context
-                .database
-                .insert_checked_invite(
-                    &unchecked_invite_code,
-                    is_permalink,
-                    is_valid,
-                    expires_at,
-                    OffsetDateTime::now_utc(),
-                )
-                .await?;
+pub async fn unschedule_invite_check(
+    context: &Context,
+    guild_id: Id<GuildMarker>,
+    profile: Option<&str>,
+) -> Result<()> {
+    let job_id = context.scheduled_checks.write().remove(&ScheduledCheck {
+        guild_id,
+        profile: profile.map(ToOwned::to_owned),
+    });
+
+    if let Some(job_id) = job_id {
+        context.scheduler.remove(&job_id).await?;
+    }
+
+    Ok(())
+}
+
+pub async fn unschedule_invite_checks(
+    context: &Context,
+    guild_id: Id<GuildMarker>,
+) -> Result<()> {
+    let job_ids = {
+        let mut scheduled_checks = context.scheduled_checks.write();
+        let guild_scheduled_checks = scheduled_checks
+            .keys()
+            .filter(|scheduled_check| scheduled_check.guild_id.eq(&guild_id))
+            .cloned()
+            .collect::<Vec<ScheduledCheck>>();
+
+        guild_scheduled_checks
+            .into_iter()
+            .filter_map(|scheduled_check| scheduled_checks.remove(&scheduled_check))
+            .collect::<Vec<Uuid>>()
+    };
+
+    for job_id in job_ids {
+        context.scheduler.remove(&job_id).await?;
+    }
+
+    Ok(())
+}
+
+async fn handle_scheduled_invite_check_task(
+    context: Arc<Context>,
+    guild_id: Id<GuildMarker>,
+    profile: Option<String>,
+) -> Result<()> {
+    let Err(error) = run_scheduled_invite_check(&context, guild_id, profile.clone()).await else {
+        return Ok(());
+    };
+    let Some(mut database_guild) = context.database.get_guild(guild_id).await else {
+        return Ok(());
+    };
+
+    if let Some(profile) = &profile {
+        let Some(check_profile) = context
+            .database
+            .get_check_profile(guild_id, profile)
+            .await?
+        else {
+            return Ok(());
+        };
+
+        database_guild = database_guild.with_check_profile(check_profile);
+    }
+
+    let Some(results_channel_id) = database_guild.results_channel_id else {
+        return Ok(());
+    };
+
+    if !context
+        .cache
+        .has_minimum_channel_permissions(results_channel_id)
+    {
+        return Ok(());
+    }
+
+    let description = match &profile {
+        Some(profile) => {
+            format!("Sakura was unable to run the scheduled `{profile}` invite check. {error}")
         }
+        None => format!("Sakura was unable to run a scheduled invite check. {error}"),
+    };
+    let embed = EmbedBuilder::new()
+        .color(database_guild.embed_color as u32)
+        .description(description)
+        .build();
+
+    context
+        .http
+        .create_message(results_channel_id)
+        .embeds(&[embed])?
+        .await?;
+
+    Ok(())
+}
+
+async fn run_scheduled_invite_check(
+    context: &Context,
+    guild_id: Id<GuildMarker>,
+    profile: Option<String>,
+) -> Result<()> {
+    let invite_check = context
+        .prepare_invite_check(
+            guild_id,
+            CheckOptions {
+                profile,
+                ..Default::default()
+            },
+        )
+        .await?;
+    let results_channel_id = invite_check.results_channel_id()?;
+    let start_embed = EmbedBuilder::new()
+        .color(invite_check.embed_color())
+        .description("Sakura is running a scheduled invite check now!")
+        .build();
+
+    context
+        .http
+        .create_message(results_channel_id)
+        .embeds(&[start_embed])?
+        .await?;
+
+    let report = invite_check.run().await?;
+
+    invite_check.send_report(results_channel_id, &report).await
+}
+
+async fn handle_expiry_warnings_task(context: Arc<Context>) -> Result<()> {
+    for guild_id in context.database.get_expiry_warning_guild_ids().await? {
+        // One guild failing to receive its warnings should not stop the rest
+        send_expiry_warnings(&context, guild_id).await.ok();
+    }
+
+    Ok(())
+}
+
+async fn send_expiry_warnings(
+    context: &Context,
+    guild_id: Id<GuildMarker>,
+) -> Result<()> {
+    let Some(database_guild) = context.database.get_guild(guild_id).await else {
+        return Ok(());
+    };
+    let Some(expiry_warning_days) = database_guild.expiry_warning_days else {
+        return Ok(());
+    };
+    let Some(alerts_channel_id) = database_guild.get_alerts_channel_id() else {
+        return Ok(());
+    };
+
+    if !context
+        .cache
+        .has_minimum_channel_permissions(alerts_channel_id)
+    {
+        return Ok(());
+    }
+
+    let expiring_invites = context
+        .database
+        .get_guild_expiring_invites(guild_id, TimeDuration::days(expiry_warning_days as i64))
+        .await?;
+    let mut expiring_channels = expiring_invites
+        .into_iter()
+        .filter(|(channel_id, _)| {
+            !context
+                .cache
+                .is_ignored_channel(&database_guild, *channel_id)
+                && context
+                    .cache
+                    .get_channel(*channel_id)
+                    .is_some_and(|channel| {
+                        context.cache.get_checked_category_id(&channel).is_some()
+                    })
+        })
+        .collect::<Vec<(Id<ChannelMarker>, Vec<(String, OffsetDateTime)>)>>();
+
+    if expiring_channels.is_empty() {
+        return Ok(());
     }
 
+    // Invites are already ordered by expiry, so the channel that expires first
+    // leads
+    expiring_channels.sort_unstable_by_key(|(_, invites)| invites[0].1);
+
+    let lines = expiring_channels
+        .into_iter()
+        .map(|(channel_id, invites)| {
+            let expiry_times = invites
+                .iter()
+                .map(|(code, expires_at)| {
+                    format!(
+                        "`{code}` <t:{0}:R> (<t:{0}:f>)",
+                        expires_at.unix_timestamp()
+                    )
+                })
+                .collect::<Vec<String>>()
+                .join(", ");
+
+            format!("- <#{channel_id}> - {expiry_times}")
+        })
+        .collect();
+    let embed = get_list_embed(
+        &format!("Invites expiring within {expiry_warning_days} day(s)"),
+        lines,
+        database_guild.embed_color as u32,
+    );
+
+    context
+        .http
+        .create_message(alerts_channel_id)
+        .embeds(&[embed])?
+        .await?;
+
     Ok(())
 }
 
+async fn handle_invalid_invite_alerts_task(context: Arc<Context>) {
+    loop {
+        sleep(INVALID_INVITE_ALERT_INTERVAL).await;
+
+        for (guild_id, alerts) in context.take_due_invalid_invite_alerts() {
+            context
+                .send_invalid_invite_alerts(guild_id, alerts)
+                .await
+                .ok();
+        }
+    }
+}
+
+async fn handle_invite_resolver_task(context: Arc<Context>) {
+    let mut backoff = INVITE_RESOLVER_IDLE_INTERVAL;
+    let mut concurrency = 1;
+
+    loop {
+        let Ok(codes) = context
+            .database
+            .get_invites_to_check(
+                *INVITE_REVALIDATION_AGE,
+                *INVITE_EXPIRY_MARGIN,
+                INVITE_RESOLVER_BATCH_SIZE,
+            )
+            .await
+        else {
+            sleep(backoff).await;
+            backoff = min(backoff * 2, INVITE_RESOLVER_MAX_BACKOFF);
+
+            continue;
+        };
+
+        if codes.is_empty() {
+            sleep(INVITE_RESOLVER_IDLE_INTERVAL).await;
+
+            continue;
+        }
+
+        // Each batch runs as many requests at once as the bucket has left, but no more
+        // than the resolver has worked its way up to without being rate limited
+        let remaining = context.wait_for_invite_bucket().await.unwrap_or(1).max(1) as usize;
+        let semaphore = Arc::new(Semaphore::new(min(concurrency, remaining)));
+        let mut join_set = JoinSet::new();
+
+        for code in codes {
+            let Ok(permit) = semaphore.clone().acquire_owned().await else {
+                return;
+            };
+            let context = context.clone();
+
+            join_set.spawn(async move {
+                let _permit = permit;
+                let resolution = context.resolve_invite(&code).await;
+
+                // Transient failures leave the invite as it is so that it is picked up again
+                if resolution.status.is_transient() {
+                    context
+                        .database
+                        .insert_failed_invite_attempt(&code, &resolution)
+                        .await
+                        .ok();
+
+                    return false;
+                }
+
+                let Ok(is_alert_due) = context
+                    .database
+                    .insert_checked_invite(&code, &resolution, OffsetDateTime::now_utc())
+                    .await
+                else {
+                    return false;
+                };
+
+                // Only alert the first time an invite is found to be invalid, not every time
+                // it is revalidated or posted again afterwards
+                if is_alert_due {
+                    context
+                        .queue_invalid_invite_alerts(&code, resolution.status)
+                        .await
+                        .ok();
+                }
+
+                true
+            });
+        }
+
+        let mut has_failures = false;
+
+        while let Some(result) = join_set.join_next().await {
+            has_failures |= !result.unwrap_or_default();
+        }
+
+        concurrency = if context.invite_rate_limited.swap(false, Ordering::Relaxed) {
+            max(concurrency / 2, 1)
+        } else {
+            min(concurrency * 2, INVITE_RESOLVER_MAX_CONCURRENCY)
+        };
+
+        // Back off while Discord or the database is having trouble instead of retrying
+        // the same invites in a tight loop
+        if has_failures {
+            sleep(backoff).await;
+            backoff = min(backoff * 2, INVITE_RESOLVER_MAX_BACKOFF);
+        } else {
+            backoff = INVITE_RESOLVER_IDLE_INTERVAL;
+        }
+    }
+}
+
 async fn handle_recycle_invites_task(context: Arc<Context>) -> Result<()> {
     context.database.remove_old_invites().await?;
 
     let removed_ids = context.database.remove_old_messages().await?;
 
     for (guild_id, channel_ids) in removed_ids.into_iter() {
-        let Some(cached_guild) = context.cache.get_guild(guild_id) else {
+        let Some(database_guild) = context.database.get_guild(guild_id).await else {
             continue;
         };
-        let cached_guild_invite_check_category_ids =
-            cached_guild.invite_check_category_ids.read().clone();
 
-        context.cache.update_guild(
-            guild_id,
-            GuildUpdate {
-                in_check: Some(true),
-                ..Default::default()
-            },
-        );
+        let Ok(guild_job) = context
+            .cache
+            .lock_guild(guild_id, GuildJobKind::RecycleInvites)
+        else {
+            continue;
+        };
 
         for channel_id in channel_ids {
             sleep(Duration::from_millis(500)).await;
 
+            if guild_job.job.is_cancelled() {
+                break;
+            }
+
             let Some(channel) = context.cache.get_channel(channel_id) else {
                 continue;
             };
-            let Some(parent_id) = channel.parent_id else {
+            let Some(parent_id) = context.cache.get_checked_category_id(&channel) else {
                 continue;
             };
 
-            if !cached_guild_invite_check_category_ids.contains(&parent_id) {
-                continue;
-            }
-
-            let messages = context
-                .http
-                .channel_messages(channel_id)
-                .limit(10)?
-                .await?
-                .model()
+            context
+                .scan_channel(&database_guild, channel_id, parent_id)
                 .await?;
-
-            for message in messages {
-                let invite_codes = get_invite_codes(message.content, message.embeds);
-
-                for invite_code in invite_codes.iter() {
-                    context
-                        .database
-                        .insert_unchecked_invite(invite_code)
-                        .await?;
-                }
-
-                context
-                    .database
-                    .insert_message(guild_id, channel_id, message.id, parent_id, invite_codes)
-                    .await?;
-            }
         }
-
-        context.cache.update_guild(
-            guild_id,
-            GuildUpdate {
-                in_check: Some(false),
-                ..Default::default()
-            },
-        );
     }
 
     Ok(())
@@ -0,0 +1,21 @@
+use std::time::Instant;
+
+use twilight_model::id::{
+    marker::{ChannelMarker, MessageMarker},
+    Id,
+};
+
+use super::database::InviteStatus;
+
+pub struct InvalidInviteAlert {
+    pub channel_id: Id<ChannelMarker>,
+    pub code: String,
+    pub message_id: Id<MessageMarker>,
+    pub status: InviteStatus,
+}
+
+pub struct PendingAlerts {
+    pub alerts: Vec<InvalidInviteAlert>,
+    pub first_queued_at: Instant,
+    pub last_queued_at: Instant,
+}
@@ -1,6 +1,6 @@
 use std::{
     collections::{HashMap, HashSet},
-    sync::Arc,
+    sync::{atomic::AtomicBool, Arc},
 };
 
 use parking_lot::RwLock;
@@ -17,6 +17,7 @@ use twilight_model::{
 pub struct Cache {
     pub channels: RwLock<HashMap<Id<ChannelMarker>, Arc<Channel>>>,
     pub current_users: RwLock<HashMap<Id<GuildMarker>, Arc<CurrentUser>>>,
+    pub guild_jobs: RwLock<HashMap<Id<GuildMarker>, Vec<Arc<GuildJob>>>>,
     pub guilds: RwLock<HashMap<Id<GuildMarker>, Arc<Guild>>>,
     pub roles: RwLock<HashMap<Id<RoleMarker>, Arc<Role>>>,
     pub unavailable_guilds: RwLock<HashSet<Id<GuildMarker>>>,
@@ -31,6 +32,7 @@ pub struct Channel {
     pub parent_id: Option<Id<ChannelMarker>>,
     pub permission_overwrites: Option<Vec<PermissionOverwrite>>,
     pub position: i32,
+    pub topic: Option<String>,
 }
 
 #[derive(Default)]
@@ -40,6 +42,7 @@ pub struct ChannelUpdate {
     pub parent_id: Option<Option<Id<ChannelMarker>>>,
     pub permission_overwrites: Option<Option<Vec<PermissionOverwrite>>>,
     pub position: Option<i32>,
+    pub topic: Option<Option<String>>,
 }
 
 pub struct CurrentUser {
@@ -58,18 +61,43 @@ pub struct CurrentUserUpdate {
 #[derive(Debug)]
 pub struct Guild {
     pub channel_ids: RwLock<HashSet<Id<ChannelMarker>>>,
+    pub check_profile_category_ids: RwLock<HashSet<Id<ChannelMarker>>>,
+    pub check_profile_tracked_channel_ids: RwLock<HashSet<Id<ChannelMarker>>>,
     pub guild_id: Id<GuildMarker>,
-    pub in_check: bool,
     pub invite_check_category_ids: RwLock<HashSet<Id<ChannelMarker>>>,
     pub name: String,
     pub role_ids: RwLock<HashSet<Id<RoleMarker>>>,
+    pub tracked_channel_ids: RwLock<HashSet<Id<ChannelMarker>>>,
 }
 
 #[derive(Default)]
 pub struct GuildUpdate {
-    pub in_check: Option<bool>,
+    pub check_profile_category_ids: Option<HashSet<Id<ChannelMarker>>>,
+    pub check_profile_tracked_channel_ids: Option<HashSet<Id<ChannelMarker>>>,
     pub invite_check_category_ids: Option<HashSet<Id<ChannelMarker>>>,
     pub name: Option<String>,
+    pub tracked_channel_ids: Option<HashSet<Id<ChannelMarker>>>,
+}
+
+pub struct GuildJob {
+    pub cancelled: AtomicBool,
+    pub kind: GuildJobKind,
+    pub profile: Option<String>,
+    pub started_at: OffsetDateTime,
+}
+
+pub struct GuildJobGuard<'a> {
+    pub cache: &'a Cache,
+    pub guild_id: Id<GuildMarker>,
+    pub job: Arc<GuildJob>,
+}
+
+#[derive(Clone, Copy)]
+pub enum GuildJobKind {
+    AddCategoryChannel,
+    AddTrackedChannel,
+    InviteCheck,
+    RecycleInvites,
 }
 
 pub struct Role {
@@ -0,0 +1,85 @@
+use std::{collections::HashMap, sync::Arc};
+
+use time::OffsetDateTime;
+use twilight_model::id::{marker::ChannelMarker, Id};
+
+use super::{
+    cache::{self, GuildJobGuard},
+    context::Context,
+    database::{
+        self,
+        ChannelRule,
+        InviteCheckChannel,
+        InviteCheckChannelStatus,
+        InviteCountingMode,
+        InviteLocation,
+        InviteStatus,
+        InviteTarget,
+    },
+};
+
+pub struct CategoryReport {
+    pub name: String,
+    pub channels: Vec<InviteCheckChannel>,
+}
+
+pub struct ChannelChange {
+    pub channel_id: Id<ChannelMarker>,
+    pub previous_status: Option<InviteCheckChannelStatus>,
+    pub status: Option<InviteCheckChannelStatus>,
+}
+
+#[derive(Default)]
+pub struct CheckOptions {
+    pub diff: bool,
+    pub profile: Option<String>,
+}
+
+pub struct DuplicateGuild {
+    pub channel_ids: Vec<Id<ChannelMarker>>,
+    pub target: InviteTarget,
+}
+
+pub struct CheckReport {
+    pub categories: Vec<CategoryReport>,
+    pub changes: Option<Vec<ChannelChange>>,
+    pub channel_rules: HashMap<Id<ChannelMarker>, ChannelRule>,
+    pub check_id: i64,
+    pub duplicate_guilds: Vec<DuplicateGuild>,
+    pub embed_color: u32,
+    pub end_time: OffsetDateTime,
+    pub invalid_invite_locations: HashMap<Id<ChannelMarker>, Vec<(String, Option<InviteLocation>)>>,
+    pub invite_counting_mode: InviteCountingMode,
+    pub invite_status_counts: HashMap<Id<ChannelMarker>, Vec<(Option<InviteStatus>, u16)>>,
+    pub profile: Option<String>,
+    pub require_permanent_invites: bool,
+    pub rule_violations: HashMap<Id<ChannelMarker>, Vec<RuleViolation>>,
+    pub start_time: OffsetDateTime,
+}
+
+pub struct InviteCheck<'a> {
+    pub cached_guild: Arc<cache::Guild>,
+    pub context: &'a Context,
+    pub database_guild: database::Guild,
+    pub guild_job: GuildJobGuard<'a>,
+    pub previous_channels: Option<Vec<InviteCheckChannel>>,
+    pub profile: Option<String>,
+}
+
+pub enum RuleViolation {
+    BlockedGuild {
+        target: InviteTarget,
+    },
+    DuplicateGuild {
+        channel_ids: Vec<Id<ChannelMarker>>,
+        target: InviteTarget,
+    },
+    TooFewMembers {
+        minimum_member_count: i32,
+        target: InviteTarget,
+    },
+    UnexpectedInviteCount {
+        channel_rule: ChannelRule,
+        valid_invites: i32,
+    },
+}
@@ -1,18 +1,39 @@
-use std::{collections::HashMap, sync::Arc};
+use std::{
+    collections::HashMap,
+    sync::{atomic::AtomicBool, Arc},
+};
 
 use parking_lot::RwLock;
 use time::OffsetDateTime;
+use tokio_cron_scheduler::JobScheduler;
 use twilight_gateway::Latency;
 use twilight_http::Client;
-use twilight_model::id::{marker::ApplicationMarker, Id};
+use twilight_model::id::{
+    marker::{ApplicationMarker, GuildMarker},
+    Id,
+};
+use uuid::Uuid;
 
-use super::{cache::Cache, database::Database};
+use super::{alert::PendingAlerts, cache::Cache, database::Database, shortener::InviteShortener};
 
 pub struct Context {
     pub application_id: Id<ApplicationMarker>,
     pub cache: Cache,
     pub database: Database,
     pub http: Arc<Client>,
+    // Set whenever Discord rate limits invite requests, so that the resolver can slow down
+    pub invite_rate_limited: AtomicBool,
     pub latencies: RwLock<HashMap<u64, Arc<Latency>>>,
+    pub pending_alerts: RwLock<HashMap<Id<GuildMarker>, PendingAlerts>>,
     pub ready_at: RwLock<Option<OffsetDateTime>>,
+    pub scheduled_checks: RwLock<HashMap<ScheduledCheck, Uuid>>,
+    pub scheduler: JobScheduler,
+    pub shortener: Option<InviteShortener>,
+}
+
+// Guilds have one schedule of their own and one for each check profile
+#[derive(Clone, Eq, Hash, PartialEq)]
+pub struct ScheduledCheck {
+    pub guild_id: Id<GuildMarker>,
+    pub profile: Option<String>,
 }
@@ -5,7 +5,7 @@ use serde::{Deserialize, Serialize};
 use time::OffsetDateTime;
 use tokio_postgres::types::{FromSql, ToSql};
 use twilight_model::id::{
-    marker::{ChannelMarker, GuildMarker},
+    marker::{ChannelMarker, GuildMarker, UserMarker},
     Id,
 };
 
@@ -13,6 +13,30 @@ pub struct Database {
     pub pool: Pool,
 }
 
+#[derive(Clone)]
+pub struct ChannelRule {
+    pub channel_id: Id<ChannelMarker>,
+    pub minimum_invites: Option<i32>,
+    pub maximum_invites: Option<i32>,
+    pub allow_temporary_invites: bool,
+    pub owner_id: Option<Id<UserMarker>>,
+}
+
+pub struct CheckProfile {
+    pub name: String,
+    pub category_channel_ids: HashSet<Id<ChannelMarker>>,
+    pub ignored_channel_ids: HashSet<Id<ChannelMarker>>,
+    pub embed_color: i32,
+    pub results_channel_id: Option<Id<ChannelMarker>>,
+    pub check_schedule: Option<String>,
+    pub minimum_member_count: Option<i32>,
+    pub allow_duplicate_guilds: bool,
+    pub require_permanent_invites: bool,
+    pub blocked_guild_ids: HashSet<Id<GuildMarker>>,
+    pub ignore_rules: Vec<IgnoreRule>,
+    pub tracked_channel_ids: HashSet<Id<ChannelMarker>>,
+}
+
 #[derive(Clone, Debug, FromSql, ToSql)]
 #[postgres(name = "event")]
 pub enum Event {
@@ -30,6 +54,19 @@ pub struct Guild {
     pub ignored_channel_ids: HashSet<Id<ChannelMarker>>,
     pub embed_color: i32,
     pub results_channel_id: Option<Id<ChannelMarker>>,
+    pub check_schedule: Option<String>,
+    pub minimum_member_count: Option<i32>,
+    pub allow_duplicate_guilds: bool,
+    pub blocked_guild_ids: HashSet<Id<GuildMarker>>,
+    pub require_permanent_invites: bool,
+    pub alerts_channel_id: Option<Id<ChannelMarker>>,
+    pub expiry_warning_days: Option<i32>,
+    pub invalid_invite_alerts: bool,
+    pub scan_depth: i32,
+    pub scan_author_ids: HashSet<Id<UserMarker>>,
+    pub invite_counting_mode: InviteCountingMode,
+    pub ignore_rules: Vec<IgnoreRule>,
+    pub tracked_channel_ids: HashSet<Id<ChannelMarker>>,
 }
 
 #[derive(Deserialize, Serialize)]
@@ -42,6 +79,15 @@ pub struct GuildDeletePayload {
     pub guild_id: i64,
 }
 
+#[derive(Clone, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case", tag = "kind")]
+pub enum IgnoreRule {
+    ChannelType { channel_type: u8 },
+    NameGlob { pattern: String },
+    NameRegex { pattern: String },
+    TopicKeyword { keyword: String },
+}
+
 #[derive(Deserialize, Serialize)]
 pub struct InviteCheckCreatePayload {
     pub guild_id: i64,
@@ -51,4 +97,87 @@ pub struct InviteCheckCreatePayload {
     pub valid_invites: i64,
     pub invalid_invites: i64,
     pub unknown_invites: i64,
+    #[serde(default)]
+    pub profile: Option<String>,
+}
+
+#[derive(Clone, Copy, Eq, PartialEq)]
+pub enum InviteCheckChannelStatus {
+    BreaksRules,
+    Ignored,
+    Invalid,
+    Temporary,
+    Unknown,
+    Untracked,
+    Valid,
+}
+
+#[derive(Clone)]
+pub struct InviteCheckChannel {
+    pub channel_id: Id<ChannelMarker>,
+    pub category_id: Id<ChannelMarker>,
+    pub position: i32,
+    pub valid_invites: i32,
+    pub invalid_invites: i32,
+    pub unknown_invites: i32,
+    pub temporary_invites: i32,
+    pub is_ignored: bool,
+    pub is_untracked: bool,
+    pub breaks_rules: bool,
+}
+
+#[derive(Clone, Copy, Debug, Eq, FromSql, PartialEq, ToSql)]
+#[postgres(name = "invite_counting_mode")]
+pub enum InviteCountingMode {
+    #[postgres(name = "ALL")]
+    All,
+    #[postgres(name = "FIRST")]
+    First,
+    #[postgres(name = "LATEST")]
+    Latest,
+    #[postgres(name = "PINNED")]
+    Pinned,
+}
+
+#[derive(Clone, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case", tag = "kind")]
+pub enum InviteLocation {
+    Attachment { filename: String },
+    Button { label: Option<String> },
+    Content,
+    EmbedAuthor,
+    EmbedDescription,
+    EmbedField { name: String },
+    EmbedFooter,
+    EmbedTitle,
+    EmbedUrl,
+}
+
+#[derive(Clone)]
+pub struct InviteTarget {
+    pub guild_id: Id<GuildMarker>,
+    pub name: String,
+    pub member_count: Option<i32>,
+    pub online_count: Option<i32>,
+}
+
+#[derive(Clone, Copy, Debug, Eq, FromSql, Hash, PartialEq, ToSql)]
+#[postgres(name = "invite_status")]
+pub enum InviteStatus {
+    #[postgres(name = "GUILD_UNAVAILABLE")]
+    GuildUnavailable,
+    #[postgres(name = "NETWORK_ERROR")]
+    NetworkError,
+    #[postgres(name = "RATE_LIMITED")]
+    RateLimited,
+    #[postgres(name = "UNKNOWN_INVITE")]
+    UnknownInvite,
+    #[postgres(name = "VALID")]
+    Valid,
+}
+
+pub struct Migration {
+    pub version: i32,
+    pub name: &'static str,
+    pub statement: &'static str,
 }
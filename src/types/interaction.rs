@@ -2,6 +2,7 @@ use twilight_http::client::InteractionClient;
 use twilight_model::{
     application::interaction::application_command::CommandData,
     channel::message::{Component, Embed},
+    guild::Permissions,
     id::{
         marker::{ChannelMarker, GuildMarker, InteractionMarker},
         Id,
@@ -13,6 +14,7 @@ pub struct ApplicationCommandInteraction<'a> {
     pub channel_id: Id<ChannelMarker>,
     pub data: Box<CommandData>,
     pub guild_id: Id<GuildMarker>,
+    pub member_permissions: Permissions,
     pub shard_id: u64,
 }
 
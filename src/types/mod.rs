@@ -1,8 +1,12 @@
 use crate::utility::error::Error;
 
+pub mod alert;
 pub mod cache;
+pub mod check;
 pub mod context;
 pub mod database;
 pub mod interaction;
+pub mod resolver;
+pub mod shortener;
 
 pub type Result<T> = std::result::Result<T, Error>;
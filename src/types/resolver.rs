@@ -0,0 +1,12 @@
+use time::OffsetDateTime;
+
+use super::database::{InviteStatus, InviteTarget};
+
+pub struct InviteResolution {
+    pub error_code: Option<i64>,
+    pub expires_at: Option<OffsetDateTime>,
+    pub is_permalink: bool,
+    pub is_temporary: bool,
+    pub status: InviteStatus,
+    pub target: Option<InviteTarget>,
+}
@@ -0,0 +1,22 @@
+use std::{
+    collections::HashMap,
+    time::{Duration, Instant},
+};
+
+use fancy_regex::Regex;
+use hyper::{client::HttpConnector, Client};
+use hyper_rustls::HttpsConnector;
+use parking_lot::RwLock;
+
+pub struct InviteShortener {
+    pub cache: RwLock<HashMap<String, ShortenedLink>>,
+    pub client: Client<HttpsConnector<HttpConnector>>,
+    pub domains: Vec<String>,
+    pub link_regex: Regex,
+    pub timeout: Duration,
+}
+
+pub struct ShortenedLink {
+    pub code: Option<String>,
+    pub resolved_at: Instant,
+}
@@ -2,12 +2,66 @@ use std::env;
 
 use fancy_regex::Regex;
 use once_cell::sync::Lazy;
+use time::Duration;
+use twilight_model::channel::ChannelType;
+
+use crate::{types::Result, utility::error::Error};
 
 pub static BOT_TOKEN: Lazy<String> = Lazy::new(|| env::var("BOT_TOKEN").unwrap());
 pub static DATABASE_URL: Lazy<String> = Lazy::new(|| env::var("DATABASE_URL").unwrap());
 pub static DISCORD_INVITE_REGEX: Lazy<Regex> = Lazy::new(|| {
     Regex::new(
-        r"(?i)(?:https?:\/\/)?(?:\w+\.)?discord(?:(?:app)?\.com\/invite|\.gg)\/(?<code>[a-z0-9-]+)",
+        r"(?i)(?<![a-z0-9-])(?:https?:\/\/)?(?:[a-z0-9-]+\.)?(?:discord(?:app)?\.com\/invite|discord\.gg(?:\/invite)?)\/(?<code>[a-z0-9-]+)",
     )
     .unwrap()
 });
+// Both are checked by `check_environment_variables` on startup
+pub static INVITE_EXPIRY_MARGIN: Lazy<Duration> = Lazy::new(|| {
+    Duration::minutes(get_duration_variable("INVITE_EXPIRY_MARGIN_MINUTES", 60).unwrap())
+});
+pub static INVITE_REVALIDATION_AGE: Lazy<Duration> = Lazy::new(|| {
+    Duration::hours(get_duration_variable("INVITE_REVALIDATION_AGE_HOURS", 24).unwrap())
+});
+// Redirect services whose links are followed to find the invite behind them
+pub static INVITE_SHORTENER_DOMAINS: Lazy<Vec<String>> = Lazy::new(|| {
+    env::var("INVITE_SHORTENER_DOMAINS")
+        .unwrap_or_else(|_| "dsc.gg,discord.io,discord.link,discord.me,invite.gg".to_owned())
+        .split(',')
+        .map(|domain| domain.trim().to_lowercase())
+        .filter(|domain| !domain.is_empty())
+        .collect()
+});
+
+// Jobs that hold a guild's lock for longer than this are assumed to be stuck
+pub const GUILD_JOB_TIMEOUT: Duration = Duration::minutes(30);
+
+// Tracked channels outside of added categories are reported under this name
+pub const TRACKED_CHANNELS_CATEGORY_NAME: &str = "Tracked channels";
+
+// twilight does not know about media channels yet
+pub const GUILD_MEDIA_CHANNEL_TYPE: ChannelType = ChannelType::Unknown(16);
+
+pub fn check_environment_variables() -> Result<()> {
+    get_duration_variable("INVITE_EXPIRY_MARGIN_MINUTES", 60)?;
+    get_duration_variable("INVITE_REVALIDATION_AGE_HOURS", 24)?;
+
+    Ok(())
+}
+
+fn get_duration_variable(
+    name: &str,
+    default: i64,
+) -> Result<i64> {
+    let Ok(value) = env::var(name) else {
+        return Ok(default);
+    };
+
+    match value.trim().parse::<i64>() {
+        Ok(duration) if duration >= 0 => Ok(duration),
+        _ => {
+            Err(Error::Custom(format!(
+                "{name} has to be a whole number of at least 0, not \"{value}\""
+            )))
+        }
+    }
+}
@@ -19,6 +19,8 @@ pub enum Error {
     InteractionOptions(#[from] twilight_interactions::error::ParseError),
     #[error("Job scheduler")]
     JobScheduler(#[from] tokio_cron_scheduler::JobSchedulerError),
+    #[error("Unable to run database migrations: {0}")]
+    Migration(String),
     #[error("Unable to validate message")]
     MessageValidation(#[from] twilight_validate::message::MessageValidationError),
     #[error("Unable to parse integer")]
@@ -21,6 +21,7 @@ pub async fn connect(
     let intents = Intents::GUILDS | Intents::GUILD_MESSAGES | Intents::MESSAGE_CONTENT;
     let event_types = EventTypeFlags::CHANNEL_CREATE
         | EventTypeFlags::CHANNEL_DELETE
+        | EventTypeFlags::CHANNEL_PINS_UPDATE
         | EventTypeFlags::CHANNEL_UPDATE
         | EventTypeFlags::GATEWAY_HEARTBEAT
         | EventTypeFlags::GATEWAY_HEARTBEAT_ACK
@@ -39,6 +40,10 @@ pub async fn connect(
         | EventTypeFlags::ROLE_CREATE
         | EventTypeFlags::ROLE_DELETE
         | EventTypeFlags::ROLE_UPDATE
+        | EventTypeFlags::THREAD_CREATE
+        | EventTypeFlags::THREAD_DELETE
+        | EventTypeFlags::THREAD_LIST_SYNC
+        | EventTypeFlags::THREAD_UPDATE
         | EventTypeFlags::UNAVAILABLE_GUILD;
     let config = Config::builder(BOT_TOKEN.to_owned(), intents)
         .event_types(event_types)
@@ -0,0 +1,85 @@
+use crate::utility::constants::DISCORD_INVITE_REGEX;
+
+// Characters that render as nothing but stop a link from being recognised
+const INVISIBLE_CHARACTERS: &[char] = &[
+    '\u{00AD}', '\u{034F}', '\u{180E}', '\u{200B}', '\u{200C}', '\u{200D}', '\u{200E}', '\u{200F}',
+    '\u{202A}', '\u{202B}', '\u{202C}', '\u{202D}', '\u{202E}', '\u{2060}', '\u{2061}', '\u{2062}',
+    '\u{2063}', '\u{2064}', '\u{FEFF}',
+];
+
+pub fn normalize_invite_text(text: &str) -> String {
+    let mut normalized = String::with_capacity(text.len());
+    let mut characters = text
+        .chars()
+        .filter(|character| !INVISIBLE_CHARACTERS.contains(character))
+        .peekable();
+
+    while let Some(character) = characters.next() {
+        // Markdown escapes such as `discord\.gg\/code` display without the backslash
+        if character == '\\'
+            && characters
+                .peek()
+                .is_some_and(|next_character| next_character.is_ascii_punctuation())
+        {
+            if let Some(next_character) = characters.next() {
+                normalized.push(next_character);
+            }
+
+            continue;
+        }
+
+        normalized.push(character);
+    }
+
+    normalized
+}
+
+pub fn parse_invite_codes(text: &str) -> Vec<String> {
+    let normalized = normalize_invite_text(text);
+    let mut invite_codes = Vec::new();
+
+    for captures in DISCORD_INVITE_REGEX.captures_iter(&normalized).flatten() {
+        if let Some(code) = captures.name("code") {
+            let code = code.as_str().to_owned();
+
+            if !invite_codes.contains(&code) {
+                invite_codes.push(code);
+            }
+        }
+    }
+
+    invite_codes
+}
+
+#[cfg(test)]
+mod tests {
+    use serde::Deserialize;
+
+    use super::parse_invite_codes;
+
+    #[derive(Deserialize)]
+    struct Sample {
+        codes: Vec<String>,
+        text: String,
+    }
+
+    #[test]
+    fn corpus() {
+        let samples: Vec<Sample> = serde_json::from_str(include_str!("corpus.json")).unwrap();
+        let failures = samples
+            .iter()
+            .filter_map(|sample| {
+                let codes = parse_invite_codes(&sample.text);
+
+                (codes != sample.codes).then(|| {
+                    format!(
+                        "{:?}: expected {:?}, got {:?}",
+                        sample.text, sample.codes, codes
+                    )
+                })
+            })
+            .collect::<Vec<String>>();
+
+        assert!(failures.is_empty(), "{}", failures.join("\n"));
+    }
+}
@@ -1,30 +1,140 @@
-use std::collections::HashSet;
+use std::collections::BTreeMap;
 
-use twilight_model::channel::message::Embed;
+use twilight_model::channel::{
+    message::{component::Component, Embed},
+    Attachment,
+};
+use twilight_util::builder::embed::EmbedBuilder;
 
-use crate::utility::constants::DISCORD_INVITE_REGEX;
+use crate::{types::database::InviteLocation, utility::invite::parse_invite_codes};
+
+const LIST_EMBED_DESCRIPTION_LENGTH: usize = 4000;
 
 pub fn get_invite_codes(
-    content: String,
-    embeds: Vec<Embed>,
-) -> HashSet<String> {
-    let mut message_strings = embeds
-        .into_iter()
-        .map(|embed| embed.description.unwrap_or_default())
-        .collect::<Vec<String>>();
-
-    message_strings.push(content);
-
-    let message_string = message_strings.join(" ");
-    let mut invite_codes = HashSet::new();
-
-    for captures_result in DISCORD_INVITE_REGEX.captures_iter(&message_string) {
-        if let Ok(captures) = captures_result {
-            if let Some(code) = captures.get(1) {
-                invite_codes.insert(code.as_str().to_owned());
-            }
+    content: &str,
+    embeds: &[Embed],
+    components: &[Component],
+    attachments: &[Attachment],
+) -> BTreeMap<String, InviteLocation> {
+    let mut invite_codes = BTreeMap::new();
+
+    // The first place a code is found in wins, so the content takes precedence
+    for (text, location) in get_invite_sources(content, embeds, components, attachments) {
+        for code in parse_invite_codes(text) {
+            invite_codes.entry(code).or_insert_with(|| location.clone());
         }
     }
 
     invite_codes
 }
+
+pub fn get_invite_sources<'a>(
+    content: &'a str,
+    embeds: &'a [Embed],
+    components: &'a [Component],
+    attachments: &'a [Attachment],
+) -> Vec<(&'a str, InviteLocation)> {
+    let mut sources = vec![(content, InviteLocation::Content)];
+
+    for embed in embeds {
+        if let Some(title) = &embed.title {
+            sources.push((title, InviteLocation::EmbedTitle));
+        }
+
+        if let Some(url) = &embed.url {
+            sources.push((url, InviteLocation::EmbedUrl));
+        }
+
+        if let Some(description) = &embed.description {
+            sources.push((description, InviteLocation::EmbedDescription));
+        }
+
+        for field in embed.fields.iter() {
+            for text in [&field.name, &field.value] {
+                sources.push((
+                    text,
+                    InviteLocation::EmbedField {
+                        name: field.name.clone(),
+                    },
+                ));
+            }
+        }
+
+        if let Some(author) = &embed.author {
+            sources.push((&author.name, InviteLocation::EmbedAuthor));
+
+            if let Some(url) = &author.url {
+                sources.push((url, InviteLocation::EmbedAuthor));
+            }
+        }
+
+        if let Some(footer) = &embed.footer {
+            sources.push((&footer.text, InviteLocation::EmbedFooter));
+        }
+    }
+
+    push_component_sources(&mut sources, components);
+
+    for attachment in attachments {
+        if let Some(description) = &attachment.description {
+            sources.push((
+                description,
+                InviteLocation::Attachment {
+                    filename: attachment.filename.clone(),
+                },
+            ));
+        }
+    }
+
+    sources
+}
+
+pub fn get_list_embed(
+    title: &str,
+    lines: Vec<String>,
+    color: u32,
+) -> Embed {
+    let line_count = lines.len();
+    let mut description = String::new();
+
+    for (index, line) in lines.into_iter().enumerate() {
+        if description.len() + line.len() + 1 > LIST_EMBED_DESCRIPTION_LENGTH {
+            description.push_str(&format!("...and **{}** more", line_count - index));
+
+            break;
+        }
+
+        description.push_str(&line);
+        description.push('\n');
+    }
+
+    EmbedBuilder::new()
+        .color(color)
+        .description(description)
+        .title(format!("{title} ({line_count})"))
+        .build()
+}
+
+fn push_component_sources<'a>(
+    sources: &mut Vec<(&'a str, InviteLocation)>,
+    components: &'a [Component],
+) {
+    for component in components {
+        match component {
+            Component::ActionRow(action_row) => {
+                push_component_sources(sources, &action_row.components)
+            }
+            Component::Button(button) => {
+                if let Some(url) = &button.url {
+                    sources.push((
+                        url,
+                        InviteLocation::Button {
+                            label: button.label.clone(),
+                        },
+                    ));
+                }
+            }
+            _ => {}
+        }
+    }
+}
@@ -1,5 +1,6 @@
 pub mod constants;
 pub mod error;
 pub mod gateway;
+pub mod invite;
 pub mod message;
 pub mod time;
@@ -1,3 +1,61 @@
+pub fn get_cron_expression(check_schedule: &str) -> String {
+    // Schedules are stored as five-field cron expressions, but the scheduler
+    // expects seconds too
+    let mut fields = check_schedule
+        .split(' ')
+        .map(ToOwned::to_owned)
+        .collect::<Vec<String>>();
+
+    if let Some(day_of_week) = fields.get_mut(4) {
+        *day_of_week = get_cron_day_of_week(day_of_week);
+    }
+
+    format!("0 {}", fields.join(" "))
+}
+
+// Cron usually numbers weekdays from 0 (or 7) for Sunday to 6 for Saturday,
+// while the scheduler numbers them from 1 for Sunday to 7 for Saturday. Names
+// are left as they are
+fn get_cron_day_of_week(day_of_week: &str) -> String {
+    let shift = |day: &str| {
+        day.parse::<u8>()
+            .map_or(day.to_owned(), |day| (day % 7 + 1).to_string())
+    };
+
+    day_of_week
+        .split(',')
+        .map(|item| {
+            let (range, step) = match item.split_once('/') {
+                Some((range, step)) => (range, Some(step)),
+                None => (item, None),
+            };
+            let suffix = step.map_or(String::new(), |step| format!("/{step}"));
+
+            match range.split_once('-') {
+                // Sunday can end a range as 7, which would wrap around to 1
+                Some((start, "7")) => {
+                    let includes_sunday = match (start.parse::<u8>(), step) {
+                        (Ok(start), Some(step)) => {
+                            step.parse::<u8>()
+                                .is_ok_and(|step| step > 0 && (7 - start.min(7)) % step == 0)
+                        }
+                        _ => true,
+                    };
+
+                    if includes_sunday {
+                        format!("{}-7{suffix},1", shift(start))
+                    } else {
+                        format!("{}-7{suffix}", shift(start))
+                    }
+                }
+                Some((start, end)) => format!("{}-{}{suffix}", shift(start), shift(end)),
+                None => format!("{}{suffix}", shift(range)),
+            }
+        })
+        .collect::<Vec<String>>()
+        .join(",")
+}
+
 pub fn humanize(mut milliseconds: u128) -> String {
     let days = milliseconds / 86_400_000;
     milliseconds = milliseconds % 86_400_000;